@@ -1,5 +1,9 @@
 use std::cmp::Ordering::{Equal, Greater, Less};
 use std::fmt::Debug;
+use std::ops::{Bound, RangeBounds};
+
+#[cfg(feature = "serde")]
+mod serde_impl;
 
 #[derive(Debug)]
 pub struct BST<K, V> {
@@ -58,6 +62,93 @@ impl<K: Ord + Debug, V: Debug> BST<K, V> {
             self.root = root_node.delete(key);
         }
     }
+
+    /// In-order iterator over `(key, value)` pairs
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        let mut iter = Iter { stack: Vec::new() };
+        iter.push_left(self.root.as_deref());
+        iter
+    }
+
+    /// The entry with the smallest key
+    pub fn first(&self) -> Option<(&K, &V)> {
+        let mut node = self.root.as_deref()?;
+        while let Some(left) = node.left.as_deref() {
+            node = left;
+        }
+        Some((&node.key, &node.value))
+    }
+
+    /// The entry with the largest key
+    pub fn last(&self) -> Option<(&K, &V)> {
+        let mut node = self.root.as_deref()?;
+        while let Some(right) = node.right.as_deref() {
+            node = right;
+        }
+        Some((&node.key, &node.value))
+    }
+
+    /// In-order iterator over the entries whose keys are in `range`
+    pub fn range<R: RangeBounds<K>>(&self, range: R) -> impl Iterator<Item = (&K, &V)> {
+        let mut iter = Iter { stack: Vec::new() };
+        // Like push_left, but skipping subtrees below the start
+        let mut node = self.root.as_deref();
+        while let Some(n) = node {
+            let after_start = match range.start_bound() {
+                Bound::Included(start) => &n.key >= start,
+                Bound::Excluded(start) => &n.key > start,
+                Bound::Unbounded => true,
+            };
+            if after_start {
+                iter.stack.push(n);
+                node = n.left.as_deref();
+            } else {
+                node = n.right.as_deref();
+            }
+        }
+        iter.take_while(move |(key, _)| range.contains(key))
+    }
+
+    /// The first entry for which `pred` is false, where `pred` is true for
+    /// every entry before some point and false from then on. Calls `pred`
+    /// once per level of the tree.
+    pub fn partition_point<F: FnMut(&K, &V) -> bool>(&self, mut pred: F) -> Option<(&K, &V)> {
+        let mut node = self.root.as_deref();
+        let mut found = None;
+        while let Some(n) = node {
+            if pred(&n.key, &n.value) {
+                node = n.right.as_deref();
+            } else {
+                found = Some((&n.key, &n.value));
+                node = n.left.as_deref();
+            }
+        }
+        found
+    }
+}
+
+pub struct Iter<'a, K, V> {
+    // Nodes whose left subtree has been visited but not themselves
+    stack: Vec<&'a BSTNode<K, V>>,
+}
+
+impl<'a, K, V> Iter<'a, K, V> {
+    fn push_left(&mut self, mut node: Option<&'a BSTNode<K, V>>) {
+        while let Some(n) = node {
+            self.stack.push(n);
+            node = n.left.as_deref();
+        }
+    }
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        self.push_left(node.right.as_deref());
+        Some((&node.key, &node.value))
+    }
 }
 
 impl<K: Ord + Debug, V: Debug> BSTNode<K, V> {
@@ -463,6 +554,45 @@ mod tests {
         assert_eq!(root.max(), &"6");
     }
 
+    #[test]
+    fn iter() {
+        let mut n = BST::new();
+        for i in [5, 3, 8, 1, 4, 7, 9, 2, 6] {
+            n.insert(i, i * 10);
+        }
+        n.delete(&4);
+        let items: Vec<_> = n.iter().map(|(k, v)| (*k, *v)).collect();
+        assert_eq!(items, vec![(1, 10), (2, 20), (3, 30), (5, 50), (6, 60), (7, 70), (8, 80), (9, 90)]);
+        assert_eq!(BST::<i32, ()>::new().iter().next(), None);
+    }
+
+    #[test]
+    fn first_last_range() {
+        let mut n = BST::new();
+        assert_eq!(n.first(), None);
+        for i in [5, 3, 8, 1, 4, 7, 9, 2, 6] {
+            n.insert(i, i * 10);
+        }
+        assert_eq!(n.first(), Some((&1, &10)));
+        assert_eq!(n.last(), Some((&9, &90)));
+        let keys = |r: Vec<(&i32, &i32)>| r.into_iter().map(|(k, _)| *k).collect::<Vec<_>>();
+        assert_eq!(keys(n.range(3..6).collect()), vec![3, 4, 5]);
+        assert_eq!(keys(n.range(..=2).collect()), vec![1, 2]);
+        assert_eq!(keys(n.range((Bound::Excluded(7), Bound::Unbounded)).collect()), vec![8, 9]);
+        assert_eq!(n.range(20..).next(), None);
+    }
+
+    #[test]
+    fn partition_point() {
+        let mut n = BST::new();
+        for i in 0..50 {
+            n.insert(i, i * i);
+        }
+        assert_eq!(n.partition_point(|_, v| *v < 200), Some((&15, &225)));
+        assert_eq!(n.partition_point(|k, _| *k < 0), Some((&0, &0)));
+        assert_eq!(n.partition_point(|_, _| true), None);
+    }
+
     #[test]
     fn test_succesor() {
         let mut n = BST::new();
@@ -0,0 +1,44 @@
+use crate::BST;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt::Debug;
+
+/// Trees are written as an in-order sequence of `[key, value]` pairs
+/// and rebuilt by inserting them, so the shape is not preserved.
+impl<K: Ord + Debug + Serialize, V: Debug + Serialize> Serialize for BST<K, V> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+impl<'de, K, V> Deserialize<'de> for BST<K, V>
+where
+    K: Ord + Debug + Deserialize<'de>,
+    V: Debug + Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let items = Vec::<(K, V)>::deserialize(deserializer)?;
+        let mut tree = BST::new();
+        for (k, v) in items {
+            tree.insert(k, v);
+        }
+        Ok(tree)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_round_trip() {
+        let mut tree = BST::new();
+        tree.insert(2, "two".to_string());
+        tree.insert(1, "one".to_string());
+        tree.insert(3, "three".to_string());
+        let json = serde_json::to_string(&tree).unwrap();
+        assert_eq!(json, r#"[[1,"one"],[2,"two"],[3,"three"]]"#);
+        let back: BST<i32, String> = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.find(&3), Some(&"three".to_string()));
+        assert_eq!(back.iter().count(), 3);
+    }
+}
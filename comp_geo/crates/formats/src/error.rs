@@ -0,0 +1,48 @@
+use std::fmt;
+
+/// Failure to parse an input file. `line` and `column` are 1-based
+/// and point at the offending token.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub kind: ErrorKind,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ErrorKind {
+    /// A token that should have been a number.
+    InvalidNumber(String),
+    /// The line ended before all expected values were read.
+    MissingValue,
+    /// A token that does not belong at this position.
+    UnexpectedToken(String),
+    /// The input ended before the data did.
+    UnexpectedEof,
+    /// A malformed or unsupported file header.
+    InvalidHeader(String),
+    /// A vertex reference past the end of the vertex list.
+    IndexOutOfRange(usize),
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, kind: ErrorKind) -> Self {
+        Self { line, column, kind }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}: ", self.line, self.column)?;
+        match &self.kind {
+            ErrorKind::InvalidNumber(s) => write!(f, "invalid number `{s}`"),
+            ErrorKind::MissingValue => f.write_str("missing value"),
+            ErrorKind::UnexpectedToken(s) => write!(f, "unexpected `{s}`"),
+            ErrorKind::UnexpectedEof => f.write_str("unexpected end of input"),
+            ErrorKind::InvalidHeader(s) => write!(f, "invalid header: {s}"),
+            ErrorKind::IndexOutOfRange(i) => write!(f, "vertex index {i} out of range"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
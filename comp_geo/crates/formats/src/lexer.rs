@@ -0,0 +1,135 @@
+use crate::{ErrorKind, ParseError};
+use std::str::FromStr;
+
+/// A whitespace (and optionally comma) separated word with its position.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Token<'a> {
+    pub text: &'a str,
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Token<'_> {
+    pub fn error(&self, kind: ErrorKind) -> ParseError {
+        ParseError::new(self.line, self.column, kind)
+    }
+
+    pub fn parse<T: FromStr>(&self) -> Result<T, ParseError> {
+        self.text
+            .parse()
+            .map_err(|_| self.error(ErrorKind::InvalidNumber(self.text.to_string())))
+    }
+
+    pub fn unexpected(&self) -> ParseError {
+        self.error(ErrorKind::UnexpectedToken(self.text.to_string()))
+    }
+}
+
+/// One non-empty line of input after comments are stripped.
+#[derive(Debug)]
+pub(crate) struct Line<'a> {
+    pub number: usize,
+    pub tokens: Vec<Token<'a>>,
+    end_column: usize,
+}
+
+impl<'a> Line<'a> {
+    pub fn get(&self, i: usize) -> Result<Token<'a>, ParseError> {
+        self.tokens
+            .get(i)
+            .copied()
+            .ok_or(ParseError::new(self.number, self.end_column, ErrorKind::MissingValue))
+    }
+
+    pub fn parse<T: FromStr>(&self, i: usize) -> Result<T, ParseError> {
+        self.get(i)?.parse()
+    }
+
+    /// Errors on the first token past `len`.
+    pub fn expect_len(&self, len: usize) -> Result<(), ParseError> {
+        match self.tokens.get(len) {
+            Some(t) => Err(t.unexpected()),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Iterates over the non-empty lines of a line oriented format.
+pub(crate) struct Lines<'a> {
+    lines: std::iter::Enumerate<std::str::Lines<'a>>,
+    commas: bool,
+    last_line: usize,
+    line_offset: usize,
+}
+
+impl<'a> Lines<'a> {
+    /// `#` starts a comment. When `commas` is set, commas also separate tokens.
+    pub fn new(input: &'a str, commas: bool) -> Self {
+        Self { lines: input.lines().enumerate(), commas, last_line: 0, line_offset: 0 }
+    }
+
+    /// For input that starts part way through a file.
+    pub fn with_line_offset(mut self, offset: usize) -> Self {
+        self.line_offset = offset;
+        self
+    }
+
+    pub fn next_line(&mut self) -> Result<Line<'a>, ParseError> {
+        self.next()
+            .ok_or(ParseError::new(self.last_line + 1, 1, ErrorKind::UnexpectedEof))
+    }
+}
+
+impl<'a> Iterator for Lines<'a> {
+    type Item = Line<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for (i, text) in self.lines.by_ref() {
+            let number = i + 1 + self.line_offset;
+            self.last_line = number;
+            let text = text.split('#').next().unwrap_or("");
+            let tokens = split_tokens(text, number, self.commas);
+            if !tokens.is_empty() {
+                let end_column = text.chars().count() + 1;
+                return Some(Line { number, tokens, end_column });
+            }
+        }
+        None
+    }
+}
+
+fn split_tokens(text: &str, line: usize, commas: bool) -> Vec<Token<'_>> {
+    let is_sep = |c: char| c.is_whitespace() || (commas && c == ',');
+    let mut tokens = Vec::new();
+    let mut start = None;
+    for (column, (i, c)) in text.char_indices().enumerate() {
+        match (is_sep(c), start) {
+            (true, Some((s, col))) => {
+                tokens.push(Token { text: &text[s..i], line, column: col });
+                start = None;
+            }
+            (false, None) => start = Some((i, column + 1)),
+            _ => {}
+        }
+    }
+    if let Some((s, col)) = start {
+        tokens.push(Token { text: &text[s..], line, column: col });
+    }
+    tokens
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn positions() {
+        let mut lines = Lines::new("# header\n\n  1, 2 # trailing\nx", true);
+        let line = lines.next_line().unwrap();
+        assert_eq!(line.number, 3);
+        assert_eq!(line.tokens.iter().map(|t| (t.text, t.column)).collect::<Vec<_>>(), vec![("1", 3), ("2", 6)]);
+        assert_eq!(line.get(2).unwrap_err(), ParseError::new(3, 8, ErrorKind::MissingValue));
+        assert_eq!(lines.next_line().unwrap().parse::<i32>(0).unwrap_err(), ParseError::new(4, 1, ErrorKind::InvalidNumber("x".into())));
+        assert_eq!(lines.next_line().unwrap_err(), ParseError::new(5, 1, ErrorKind::UnexpectedEof));
+    }
+}
@@ -0,0 +1,16 @@
+mod error;
+pub use error::{ErrorKind, ParseError};
+
+mod lexer;
+
+mod mesh;
+pub use mesh::{read_off, read_ply, write_off, write_ply, Mesh, PlyFormat};
+
+mod triangle;
+pub use triangle::{read_node, read_poly, write_node, write_poly, NodeFile, PolyFile};
+
+mod wkt;
+pub use wkt::{read_wkt, Geometry};
+
+mod xyz;
+pub use xyz::{read_points, read_segments, write_points, write_segments};
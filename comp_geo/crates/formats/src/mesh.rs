@@ -0,0 +1,499 @@
+use crate::lexer::{Lines, Token};
+use crate::xyz::parse_point;
+use crate::{ErrorKind, ParseError};
+use num_traits::{NumCast, ToPrimitive};
+use point::Point;
+use std::fmt::Display;
+use std::io::{self, Write};
+use std::str::FromStr;
+
+/// Indexed polygon mesh, as stored in OFF and PLY files.
+/// Faces list 0-based indices into `vertices`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Mesh<N> {
+    pub vertices: Vec<Point<3, N>>,
+    pub faces: Vec<Vec<usize>>,
+}
+
+/// Reads an OFF file. Per-face colors after the vertex indices are ignored.
+/// ```
+/// let mesh = formats::read_off::<i32>("OFF\n3 1 0\n0 0 0\n1 0 0\n0 1 0\n3 0 1 2\n").unwrap();
+/// assert_eq!(mesh.vertices[1], [1, 0, 0].into());
+/// assert_eq!(mesh.faces, vec![vec![0, 1, 2]]);
+/// ```
+pub fn read_off<N: FromStr>(input: &str) -> Result<Mesh<N>, ParseError> {
+    let mut lines = Lines::new(input, false);
+    let first = lines.next_line()?;
+    let magic = first.get(0)?;
+    if magic.text != "OFF" {
+        return Err(magic.error(ErrorKind::InvalidHeader("expected `OFF`".into())));
+    }
+    // Counts may share the first line with the keyword
+    let (counts, offset) = if first.tokens.len() > 1 { (first, 1) } else { (lines.next_line()?, 0) };
+    let n_vertices: usize = counts.parse(offset)?;
+    let n_faces: usize = counts.parse(offset + 1)?;
+    counts.get(offset + 2)?.parse::<usize>()?;
+    counts.expect_len(offset + 3)?;
+
+    // The counts are not trusted to size allocations; a count past the end
+    // of the input fails when the lines run out
+    let mut vertices = Vec::new();
+    for _ in 0..n_vertices {
+        let line = lines.next_line()?;
+        vertices.push(parse_point(&line, 0)?);
+        line.expect_len(3)?;
+    }
+    let mut faces = Vec::new();
+    for _ in 0..n_faces {
+        let line = lines.next_line()?;
+        let n: usize = line.parse(0)?;
+        let face = (1..=n)
+            .map(|i| vertex_index(line.get(i)?, n_vertices))
+            .collect::<Result<_, _>>()?;
+        faces.push(face);
+    }
+    match lines.next() {
+        Some(line) => Err(line.tokens[0].unexpected()),
+        None => Ok(Mesh { vertices, faces }),
+    }
+}
+
+fn vertex_index(token: Token, n_vertices: usize) -> Result<usize, ParseError> {
+    let i: usize = token.parse()?;
+    if i < n_vertices {
+        Ok(i)
+    } else {
+        Err(token.error(ErrorKind::IndexOutOfRange(i)))
+    }
+}
+
+pub fn write_off<W: Write, N: Display>(w: &mut W, mesh: &Mesh<N>) -> io::Result<()> {
+    writeln!(w, "OFF")?;
+    writeln!(w, "{} {} 0", mesh.vertices.len(), mesh.faces.len())?;
+    for v in &mesh.vertices {
+        writeln!(w, "{} {} {}", v.coords[0], v.coords[1], v.coords[2])?;
+    }
+    for f in &mesh.faces {
+        write!(w, "{}", f.len())?;
+        for i in f {
+            write!(w, " {i}")?;
+        }
+        writeln!(w)?;
+    }
+    Ok(())
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PlyFormat {
+    Ascii,
+    BinaryLittleEndian,
+    BinaryBigEndian,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum PlyType {
+    I8,
+    U8,
+    I16,
+    U16,
+    I32,
+    U32,
+    F32,
+    F64,
+}
+
+impl PlyType {
+    fn parse(token: Token) -> Result<Self, ParseError> {
+        Ok(match token.text {
+            "char" | "int8" => PlyType::I8,
+            "uchar" | "uint8" => PlyType::U8,
+            "short" | "int16" => PlyType::I16,
+            "ushort" | "uint16" => PlyType::U16,
+            "int" | "int32" => PlyType::I32,
+            "uint" | "uint32" => PlyType::U32,
+            "float" | "float32" => PlyType::F32,
+            "double" | "float64" => PlyType::F64,
+            _ => return Err(token.error(ErrorKind::InvalidHeader(format!("unknown type `{}`", token.text)))),
+        })
+    }
+
+    fn size(self) -> usize {
+        match self {
+            PlyType::I8 | PlyType::U8 => 1,
+            PlyType::I16 | PlyType::U16 => 2,
+            PlyType::I32 | PlyType::U32 | PlyType::F32 => 4,
+            PlyType::F64 => 8,
+        }
+    }
+}
+
+#[derive(Debug)]
+enum Property {
+    Scalar(String, PlyType),
+    List(String, PlyType, PlyType),
+}
+
+#[derive(Debug)]
+struct Element {
+    name: String,
+    count: usize,
+    properties: Vec<Property>,
+    line: usize,
+}
+
+/// Source of property values in the body of a PLY file. Errors point at
+/// the last value read; for binary bodies the column is the 1-based byte
+/// offset into the body and the line is the one after `end_header`.
+enum Body<'a> {
+    Ascii { tokens: std::vec::IntoIter<Token<'a>>, line: usize, column: usize },
+    Binary { bytes: &'a [u8], pos: usize, last: usize, big_endian: bool, line: usize },
+}
+
+impl Body<'_> {
+    fn read(&mut self, ty: PlyType) -> Result<f64, ParseError> {
+        match self {
+            Body::Ascii { tokens, line, column } => {
+                let token = tokens.next().ok_or(ParseError::new(*line + 1, 1, ErrorKind::UnexpectedEof))?;
+                (*line, *column) = (token.line, token.column);
+                token.parse()
+            }
+            Body::Binary { bytes, pos, last, big_endian, line } => {
+                let size = ty.size();
+                let raw = bytes
+                    .get(*pos..*pos + size)
+                    .ok_or(ParseError::new(*line, *pos + 1, ErrorKind::UnexpectedEof))?;
+                *last = *pos;
+                *pos += size;
+                // Normalize to little endian, zero padded to 8 bytes
+                let mut b = [0u8; 8];
+                b[..size].copy_from_slice(raw);
+                if *big_endian {
+                    b[..size].reverse();
+                }
+                Ok(match ty {
+                    PlyType::I8 => b[0] as i8 as f64,
+                    PlyType::U8 => b[0] as f64,
+                    PlyType::I16 => i16::from_le_bytes([b[0], b[1]]) as f64,
+                    PlyType::U16 => u16::from_le_bytes([b[0], b[1]]) as f64,
+                    PlyType::I32 => i32::from_le_bytes([b[0], b[1], b[2], b[3]]) as f64,
+                    PlyType::U32 => u32::from_le_bytes([b[0], b[1], b[2], b[3]]) as f64,
+                    PlyType::F32 => f32::from_le_bytes([b[0], b[1], b[2], b[3]]) as f64,
+                    PlyType::F64 => f64::from_le_bytes(b),
+                })
+            }
+        }
+    }
+
+    fn error(&self, kind: ErrorKind) -> ParseError {
+        match self {
+            Body::Ascii { line, column, .. } => ParseError::new(*line, *column, kind),
+            Body::Binary { last, line, .. } => ParseError::new(*line, *last + 1, kind),
+        }
+    }
+}
+
+fn read_ply_header(header: &str) -> Result<(PlyFormat, Vec<Element>), ParseError> {
+    let mut lines = Lines::new(header, false);
+    let magic = lines.next_line()?;
+    if magic.get(0)?.text != "ply" {
+        return Err(magic.tokens[0].error(ErrorKind::InvalidHeader("expected `ply`".into())));
+    }
+    let mut format = None;
+    let mut elements: Vec<Element> = Vec::new();
+    for line in lines {
+        let keyword = line.tokens[0];
+        match keyword.text {
+            "comment" | "obj_info" => {}
+            "format" => {
+                let f = line.get(1)?;
+                format = Some(match f.text {
+                    "ascii" => PlyFormat::Ascii,
+                    "binary_little_endian" => PlyFormat::BinaryLittleEndian,
+                    "binary_big_endian" => PlyFormat::BinaryBigEndian,
+                    _ => return Err(f.error(ErrorKind::InvalidHeader(format!("unknown format `{}`", f.text)))),
+                });
+                line.get(2)?;
+                line.expect_len(3)?;
+            }
+            "element" => {
+                elements.push(Element {
+                    name: line.get(1)?.text.to_string(),
+                    count: line.parse(2)?,
+                    properties: Vec::new(),
+                    line: line.number,
+                });
+                line.expect_len(3)?;
+            }
+            "property" => {
+                let element = elements.last_mut().ok_or(keyword.unexpected())?;
+                let property = if line.get(1)?.text == "list" {
+                    line.expect_len(5)?;
+                    Property::List(line.get(4)?.text.to_string(), PlyType::parse(line.get(2)?)?, PlyType::parse(line.get(3)?)?)
+                } else {
+                    line.expect_len(3)?;
+                    Property::Scalar(line.get(2)?.text.to_string(), PlyType::parse(line.get(1)?)?)
+                };
+                element.properties.push(property);
+            }
+            "end_header" => break,
+            _ => return Err(keyword.unexpected()),
+        }
+    }
+    let format = format.ok_or(ParseError::new(1, 1, ErrorKind::InvalidHeader("missing format".into())))?;
+    Ok((format, elements))
+}
+
+/// Reads an ASCII or binary PLY file. Vertices come from the `x`, `y`
+/// and `z` properties of the `vertex` element and faces from the
+/// `vertex_indices` list of the `face` element; anything else is skipped.
+/// ```
+/// let ply = "ply\nformat ascii 1.0\nelement vertex 3\nproperty float x\nproperty float y\n\
+///            property float z\nelement face 1\nproperty list uchar int vertex_indices\nend_header\n\
+///            0 0 0\n1 0 0\n0 1 0.5\n3 0 1 2\n";
+/// let mesh = formats::read_ply::<f64>(ply.as_bytes()).unwrap();
+/// assert_eq!(mesh.vertices[2], [0.0, 1.0, 0.5].into());
+/// assert_eq!(mesh.faces, vec![vec![0, 1, 2]]);
+/// ```
+pub fn read_ply<N: NumCast>(bytes: &[u8]) -> Result<Mesh<N>, ParseError> {
+    // The header ends at the first line that is exactly `end_header`, so
+    // the words in comments cannot end it early
+    let mut body_start = None;
+    let mut line_start = 0;
+    while line_start < bytes.len() {
+        let line_end = bytes[line_start..].iter().position(|&b| b == b'\n').map_or(bytes.len(), |i| line_start + i);
+        if bytes[line_start..line_end].trim_ascii() == b"end_header" {
+            body_start = Some((line_end + 1).min(bytes.len()));
+            break;
+        }
+        line_start = line_end + 1;
+    }
+    let body_start =
+        body_start.ok_or(ParseError::new(1, 1, ErrorKind::InvalidHeader("missing end_header".into())))?;
+    let header = std::str::from_utf8(&bytes[..body_start])
+        .map_err(|_| ParseError::new(1, 1, ErrorKind::InvalidHeader("not valid text".into())))?;
+    let header_lines = header.lines().count();
+    let (format, elements) = read_ply_header(header)?;
+
+    let body = &bytes[body_start..];
+    let mut body = match format {
+        PlyFormat::Ascii => {
+            let text = std::str::from_utf8(body)
+                .map_err(|_| ParseError::new(header_lines + 1, 1, ErrorKind::UnexpectedToken("binary data".into())))?;
+            let tokens: Vec<_> = Lines::new(text, false)
+                .with_line_offset(header_lines)
+                .flat_map(|line| line.tokens)
+                .collect();
+            Body::Ascii { tokens: tokens.into_iter(), line: header_lines, column: 1 }
+        }
+        _ => Body::Binary {
+            bytes: body,
+            pos: 0,
+            last: 0,
+            big_endian: format == PlyFormat::BinaryBigEndian,
+            line: header_lines + 1,
+        },
+    };
+
+    let n_vertices = elements.iter().find(|e| e.name == "vertex").map_or(0, |e| e.count);
+    let mut mesh = Mesh { vertices: Vec::new(), faces: Vec::new() };
+    // Integer types would silently drop the fraction of a coordinate
+    let integral = N::from(0.5).and_then(|half| half.to_f64()) == Some(0.0);
+    for element in &elements {
+        let is_vertex = element.name == "vertex";
+        let is_face = element.name == "face";
+        if is_vertex {
+            for axis in ["x", "y", "z"] {
+                if !element.properties.iter().any(|p| matches!(p, Property::Scalar(n, _) if n == axis)) {
+                    let msg = format!("vertex element has no `{axis}` property");
+                    return Err(ParseError::new(element.line, 1, ErrorKind::InvalidHeader(msg)));
+                }
+            }
+        }
+        for _ in 0..element.count {
+            let mut coords = [0.0; 3];
+            for property in &element.properties {
+                match property {
+                    Property::Scalar(name, ty) => {
+                        let v = body.read(*ty)?;
+                        if let (true, Some(axis)) = (is_vertex, ["x", "y", "z"].iter().position(|a| a == name)) {
+                            coords[axis] = v;
+                        }
+                    }
+                    Property::List(name, count_ty, item_ty) => {
+                        let n = body.read(*count_ty)? as usize;
+                        let wanted = is_face && (name == "vertex_indices" || name == "vertex_index");
+                        let mut face = Vec::new();
+                        for _ in 0..n {
+                            let i = body.read(*item_ty)?;
+                            if wanted {
+                                if i < 0.0 || i as usize >= n_vertices {
+                                    return Err(body.error(ErrorKind::IndexOutOfRange(i as usize)));
+                                }
+                                face.push(i as usize);
+                            }
+                        }
+                        if wanted {
+                            mesh.faces.push(face);
+                        }
+                    }
+                }
+            }
+            if is_vertex {
+                if integral && coords.iter().any(|c| c.fract() != 0.0) {
+                    return Err(body.error(ErrorKind::InvalidNumber("fractional coordinate".into())));
+                }
+                let coords = coords.map(N::from);
+                if coords.iter().any(Option::is_none) {
+                    return Err(body.error(ErrorKind::InvalidNumber("coordinate out of range".into())));
+                }
+                mesh.vertices.push(Point { coords: coords.map(Option::unwrap) });
+            }
+        }
+    }
+    Ok(mesh)
+}
+
+/// Writes a PLY file with `double` vertex coordinates and
+/// faces as `list uchar int vertex_indices`.
+pub fn write_ply<W: Write, N: ToPrimitive>(w: &mut W, mesh: &Mesh<N>, format: PlyFormat) -> io::Result<()> {
+    let name = match format {
+        PlyFormat::Ascii => "ascii",
+        PlyFormat::BinaryLittleEndian => "binary_little_endian",
+        PlyFormat::BinaryBigEndian => "binary_big_endian",
+    };
+    writeln!(w, "ply\nformat {name} 1.0")?;
+    writeln!(w, "element vertex {}", mesh.vertices.len())?;
+    for axis in ["x", "y", "z"] {
+        writeln!(w, "property double {axis}")?;
+    }
+    writeln!(w, "element face {}", mesh.faces.len())?;
+    writeln!(w, "property list uchar int vertex_indices\nend_header")?;
+
+    let invalid = |msg: &str| io::Error::new(io::ErrorKind::InvalidInput, msg.to_string());
+    let big = format == PlyFormat::BinaryBigEndian;
+    for v in &mesh.vertices {
+        let coords = v.coords.iter().map(|c| c.to_f64()).collect::<Option<Vec<_>>>();
+        let coords = coords.ok_or_else(|| invalid("coordinate not representable as f64"))?;
+        match format {
+            PlyFormat::Ascii => writeln!(w, "{} {} {}", coords[0], coords[1], coords[2])?,
+            _ => {
+                for c in coords {
+                    w.write_all(&if big { c.to_be_bytes() } else { c.to_le_bytes() })?;
+                }
+            }
+        }
+    }
+    for f in &mesh.faces {
+        let n = u8::try_from(f.len()).map_err(|_| invalid("face with more than 255 vertices"))?;
+        let indices = f.iter().map(|&i| i32::try_from(i)).collect::<Result<Vec<_>, _>>();
+        let indices = indices.map_err(|_| invalid("vertex index does not fit in an int"))?;
+        match format {
+            PlyFormat::Ascii => {
+                write!(w, "{n}")?;
+                for i in indices {
+                    write!(w, " {i}")?;
+                }
+                writeln!(w)?;
+            }
+            _ => {
+                w.write_all(&[n])?;
+                for i in indices {
+                    w.write_all(&if big { i.to_be_bytes() } else { i.to_le_bytes() })?;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tetrahedron() -> Mesh<f64> {
+        Mesh {
+            vertices: vec![[0.0, 0.0, 0.0].into(), [1.0, 0.0, 0.0].into(), [0.0, 1.0, 0.0].into(), [0.0, 0.0, 1.5].into()],
+            faces: vec![vec![0, 2, 1], vec![0, 1, 3], vec![1, 2, 3], vec![2, 0, 3]],
+        }
+    }
+
+    #[test]
+    fn off_round_trip() {
+        let mesh = tetrahedron();
+        let mut out = Vec::new();
+        write_off(&mut out, &mesh).unwrap();
+        assert_eq!(read_off::<f64>(&String::from_utf8(out).unwrap()).unwrap(), mesh);
+        let inline = read_off::<f64>("OFF 1 0 0\n1 2 3\n").unwrap();
+        assert_eq!(inline.vertices, vec![[1.0, 2.0, 3.0].into()]);
+    }
+
+    #[test]
+    fn off_errors() {
+        let err = read_off::<f64>("OFF\n1 1 0\n0 0 0\n3 0 1 2\n").unwrap_err();
+        assert_eq!(err, ParseError::new(4, 5, ErrorKind::IndexOutOfRange(1)));
+        let err = read_off::<f64>("COFF\n").unwrap_err();
+        assert_eq!(err, ParseError::new(1, 1, ErrorKind::InvalidHeader("expected `OFF`".into())));
+        // A huge count fails when the input runs out, not when allocating
+        let err = read_off::<f64>("OFF\n99999999999999999 0 0\n").unwrap_err();
+        assert_eq!(err, ParseError::new(3, 1, ErrorKind::UnexpectedEof));
+    }
+
+    #[test]
+    fn ply_round_trip() {
+        let mesh = tetrahedron();
+        for format in [PlyFormat::Ascii, PlyFormat::BinaryLittleEndian, PlyFormat::BinaryBigEndian] {
+            let mut out = Vec::new();
+            write_ply(&mut out, &mesh, format).unwrap();
+            assert_eq!(read_ply::<f64>(&out).unwrap(), mesh, "{format:?}");
+        }
+    }
+
+    #[test]
+    fn ply_binary_types_and_extra_properties() {
+        let mut ply = b"ply\nformat binary_little_endian 1.0\ncomment generated\nelement vertex 2\n\
+property short x\nproperty uchar red\nproperty float y\nproperty int z\n\
+element face 0\nproperty list uchar uint vertex_indices\nend_header\n"
+            .to_vec();
+        for (x, red, y, z) in [(-3i16, 255u8, 0.5f32, 7i32), (4, 0, -1.25, -2)] {
+            ply.extend(x.to_le_bytes());
+            ply.push(red);
+            ply.extend(y.to_le_bytes());
+            ply.extend(z.to_le_bytes());
+        }
+        let mesh = read_ply::<f32>(&ply).unwrap();
+        assert_eq!(mesh.vertices, vec![[-3.0, 0.5, 7.0].into(), [4.0, -1.25, -2.0].into()]);
+
+        ply.truncate(ply.len() - 2);
+        let err = read_ply::<f32>(&ply).unwrap_err();
+        assert_eq!((err.line, err.column, err.kind), (12, 19, ErrorKind::UnexpectedEof));
+    }
+
+    #[test]
+    fn ply_errors() {
+        let header = "ply\nformat ascii 1.0\nelement vertex 1\nproperty float x\nproperty float y\nend_header\n";
+        let err = read_ply::<f64>(header.as_bytes()).unwrap_err();
+        assert_eq!(err, ParseError::new(3, 1, ErrorKind::InvalidHeader("vertex element has no `z` property".into())));
+
+        let ply = "ply\nformat ascii 1.0\nelement vertex 1\nproperty float x\nproperty float y\n\
+                   property float z\nend_header\n1 2 q\n";
+        let err = read_ply::<f64>(ply.as_bytes()).unwrap_err();
+        assert_eq!(err, ParseError::new(8, 5, ErrorKind::InvalidNumber("q".into())));
+
+        let ply = "ply\nformat ascii 1.0\nelement vertex 1\nproperty float x\nproperty float y\n\
+                   property float z\nend_header\n1 2.5 3\n";
+        let err = read_ply::<i32>(ply.as_bytes()).unwrap_err();
+        assert_eq!(err, ParseError::new(8, 7, ErrorKind::InvalidNumber("fractional coordinate".into())));
+        assert_eq!(read_ply::<f32>(ply.as_bytes()).unwrap().vertices, vec![[1.0, 2.5, 3.0].into()]);
+
+        let huge = "ply\nformat binary_little_endian 1.0\nelement vertex 4000000000\nproperty float x\n\
+                    property float y\nproperty float z\nend_header\n";
+        assert_eq!(read_ply::<f64>(huge.as_bytes()).unwrap_err().kind, ErrorKind::UnexpectedEof);
+
+        // Only a line of its own ends the header
+        let ply = "ply\ncomment end_header here\nformat ascii 1.0\nelement vertex 1\nproperty int x\n\
+                   property int y\nproperty int z\nend_header\n1 2 3\n";
+        assert_eq!(read_ply::<i32>(ply.as_bytes()).unwrap().vertices, vec![[1, 2, 3].into()]);
+        let err = read_ply::<i32>(b"ply\ncomment end_header\n").unwrap_err();
+        assert_eq!(err.kind, ErrorKind::InvalidHeader("missing end_header".into()));
+    }
+}
@@ -0,0 +1,277 @@
+use crate::lexer::{Line, Lines};
+use crate::xyz::parse_point;
+use crate::{ErrorKind, ParseError};
+use point::Point;
+use segment::LineSegment;
+use std::fmt::Display;
+use std::io::{self, Write};
+use std::str::FromStr;
+
+/// Contents of a Triangle `.node` file, or the vertex section of a `.poly` file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NodeFile<N> {
+    pub points: Vec<Point<2, N>>,
+    /// Per-vertex attributes, all of the same length.
+    pub attributes: Vec<Vec<f64>>,
+    pub markers: Option<Vec<i32>>,
+}
+
+/// Contents of a Triangle `.poly` file. Segment endpoints are 0-based
+/// indices into `nodes.points` regardless of the numbering in the file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PolyFile<N> {
+    pub nodes: NodeFile<N>,
+    pub segments: Vec<[usize; 2]>,
+    pub segment_markers: Option<Vec<i32>>,
+    pub holes: Vec<Point<2, N>>,
+}
+
+impl<N: Copy> PolyFile<N> {
+    pub fn line_segments(&self) -> Vec<LineSegment<2, N>> {
+        self.segments
+            .iter()
+            .map(|&[a, b]| LineSegment { start: self.nodes.points[a], end: self.nodes.points[b] })
+            .collect()
+    }
+}
+
+/// Reads a `.node` file. Vertices may be numbered from 0 or 1.
+/// ```
+/// let node = formats::read_node::<f64>("3 2 0 1\n1 0 0 1\n2 1 0 1\n3 0 1 0\n").unwrap();
+/// assert_eq!(node.points[2], [0.0, 1.0].into());
+/// assert_eq!(node.markers, Some(vec![1, 1, 0]));
+/// ```
+pub fn read_node<N: FromStr>(input: &str) -> Result<NodeFile<N>, ParseError> {
+    let mut lines = Lines::new(input, false);
+    let (nodes, _) = read_vertices(&mut lines)?;
+    match lines.next() {
+        Some(line) => Err(line.tokens[0].unexpected()),
+        None => Ok(nodes),
+    }
+}
+
+/// Reads a `.poly` file with its vertices listed inline.
+/// Regional attributes after the holes section are ignored.
+pub fn read_poly<N: FromStr>(input: &str) -> Result<PolyFile<N>, ParseError> {
+    let mut lines = Lines::new(input, false);
+    let (nodes, first_index) = read_vertices(&mut lines)?;
+    if nodes.points.is_empty() {
+        return Err(ParseError::new(1, 1, ErrorKind::InvalidHeader("vertices in a separate .node file".into())));
+    }
+
+    let header = lines.next_line()?;
+    let count: usize = header.parse(0)?;
+    let has_markers = read_flag(&header, 1)?;
+    header.expect_len(2)?;
+    let mut segments = Vec::new();
+    let mut markers = Vec::new();
+    for _ in 0..count {
+        let line = lines.next_line()?;
+        let endpoint = |i: usize| {
+            let token = line.get(i)?;
+            let index: usize = token.parse()?;
+            index
+                .checked_sub(first_index)
+                .filter(|&i| i < nodes.points.len())
+                .ok_or(token.error(ErrorKind::IndexOutOfRange(index)))
+        };
+        segments.push([endpoint(1)?, endpoint(2)?]);
+        if has_markers {
+            markers.push(line.parse(3)?);
+        }
+        line.expect_len(3 + has_markers as usize)?;
+    }
+
+    let header = lines.next_line()?;
+    let count: usize = header.parse(0)?;
+    header.expect_len(1)?;
+    let mut holes = Vec::new();
+    for _ in 0..count {
+        let line = lines.next_line()?;
+        holes.push(parse_point(&line, 1)?);
+        line.expect_len(3)?;
+    }
+
+    Ok(PolyFile {
+        nodes,
+        segments,
+        segment_markers: has_markers.then_some(markers),
+        holes,
+    })
+}
+
+fn read_flag(line: &Line, i: usize) -> Result<bool, ParseError> {
+    match line.tokens.get(i) {
+        None => Ok(false),
+        Some(t) => match t.parse::<u8>()? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(t.unexpected()),
+        },
+    }
+}
+
+// Returns the vertices and the number of the first one, 0 or 1
+fn read_vertices<N: FromStr>(lines: &mut Lines) -> Result<(NodeFile<N>, usize), ParseError> {
+    let header = lines.next_line()?;
+    let count: usize = header.parse(0)?;
+    if header.tokens.len() > 1 {
+        let dim = header.get(1)?;
+        if dim.text != "2" {
+            return Err(dim.error(ErrorKind::InvalidHeader("only 2D vertices are supported".into())));
+        }
+    }
+    let n_attributes: usize = match header.tokens.get(2) {
+        Some(t) => {
+            let n: usize = t.parse()?;
+            // Each vertex line also holds an index, two coordinates and a marker
+            if n.checked_add(4).is_none() {
+                return Err(t.error(ErrorKind::InvalidHeader("too many attributes".into())));
+            }
+            n
+        }
+        None => 0,
+    };
+    let has_markers = read_flag(&header, 3)?;
+    header.expect_len(4)?;
+
+    let mut nodes = NodeFile {
+        points: Vec::new(),
+        attributes: Vec::new(),
+        markers: has_markers.then(Vec::new),
+    };
+    let mut first_index = 0;
+    for i in 0..count {
+        let line = lines.next_line()?;
+        let number = line.get(0)?;
+        let index: usize = number.parse()?;
+        if i == 0 && index <= 1 {
+            first_index = index;
+        } else if index != first_index + i {
+            return Err(number.unexpected());
+        }
+        nodes.points.push(parse_point(&line, 1)?);
+        nodes
+            .attributes
+            .push((3..3 + n_attributes).map(|j| line.parse(j)).collect::<Result<_, _>>()?);
+        if let Some(markers) = nodes.markers.as_mut() {
+            markers.push(line.parse(3 + n_attributes)?);
+        }
+        line.expect_len(3 + n_attributes + has_markers as usize)?;
+    }
+    Ok((nodes, first_index))
+}
+
+/// Writes a `.node` file with vertices numbered from 1.
+pub fn write_node<W: Write, N: Display>(w: &mut W, nodes: &NodeFile<N>) -> io::Result<()> {
+    let n_attributes = nodes.attributes.first().map_or(0, Vec::len);
+    writeln!(w, "{} 2 {} {}", nodes.points.len(), n_attributes, nodes.markers.is_some() as u8)?;
+    for (i, p) in nodes.points.iter().enumerate() {
+        write!(w, "{} {} {}", i + 1, p.coords[0], p.coords[1])?;
+        for a in nodes.attributes.get(i).into_iter().flatten() {
+            write!(w, " {a}")?;
+        }
+        if let Some(markers) = &nodes.markers {
+            write!(w, " {}", markers[i])?;
+        }
+        writeln!(w)?;
+    }
+    Ok(())
+}
+
+/// Writes a `.poly` file with vertices listed inline and numbered from 1.
+pub fn write_poly<W: Write, N: Display>(w: &mut W, poly: &PolyFile<N>) -> io::Result<()> {
+    write_node(w, &poly.nodes)?;
+    writeln!(w, "{} {}", poly.segments.len(), poly.segment_markers.is_some() as u8)?;
+    for (i, [a, b]) in poly.segments.iter().enumerate() {
+        write!(w, "{} {} {}", i + 1, a + 1, b + 1)?;
+        if let Some(markers) = &poly.segment_markers {
+            write!(w, " {}", markers[i])?;
+        }
+        writeln!(w)?;
+    }
+    writeln!(w, "{}", poly.holes.len())?;
+    for (i, h) in poly.holes.iter().enumerate() {
+        writeln!(w, "{} {} {}", i + 1, h.coords[0], h.coords[1])?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SQUARE: &str = "\
+# A square with a square hole
+8 2 0 1
+0 0 0 1
+1 3 0 1
+2 3 3 1
+3 0 3 1
+4 1 1 0
+5 2 1 0
+6 2 2 0
+7 1 2 0
+# segments
+8 1
+0 0 1 1
+1 1 2 1
+2 2 3 1
+3 3 0 1
+4 4 5 2
+5 5 6 2
+6 6 7 2
+7 7 4 2
+1
+1 1.5 1.5
+";
+
+    #[test]
+    fn poly() {
+        let poly = read_poly::<f64>(SQUARE).unwrap();
+        assert_eq!(poly.nodes.points.len(), 8);
+        assert_eq!(poly.segments[7], [7, 4]);
+        assert_eq!(poly.segment_markers.as_ref().unwrap()[4], 2);
+        assert_eq!(poly.holes, vec![[1.5, 1.5].into()]);
+
+        let mut out = Vec::new();
+        write_poly(&mut out, &poly).unwrap();
+        assert_eq!(read_poly::<f64>(&String::from_utf8(out).unwrap()).unwrap(), poly);
+    }
+
+    #[test]
+    fn poly_segments() {
+        let poly = read_poly::<i32>("3 2\n1 0 0\n2 4 0\n3 0 4\n2 0\n1 1 2\n2 2 3\n0\n").unwrap();
+        assert_eq!(
+            poly.line_segments(),
+            vec![
+                LineSegment { start: [0, 0].into(), end: [4, 0].into() },
+                LineSegment { start: [4, 0].into(), end: [0, 4].into() },
+            ]
+        );
+        let err = read_poly::<i32>("3 2\n1 0 0\n2 4 0\n3 0 4\n1 0\n1 1 4\n0\n").unwrap_err();
+        assert_eq!(err, ParseError::new(6, 5, ErrorKind::IndexOutOfRange(4)));
+    }
+
+    #[test]
+    fn node_attributes() {
+        let node = read_node::<i32>("2 2 1 0\n0 1 1 0.5\n1 2 2 0.25\n").unwrap();
+        assert_eq!(node.attributes, vec![vec![0.5], vec![0.25]]);
+        assert_eq!(node.markers, None);
+        let mut out = Vec::new();
+        write_node(&mut out, &node).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), "2 2 1 0\n1 1 1 0.5\n2 2 2 0.25\n");
+    }
+
+    #[test]
+    fn node_errors() {
+        assert_eq!(read_node::<i32>("2 3 0 0\n").unwrap_err().kind, ErrorKind::InvalidHeader("only 2D vertices are supported".into()));
+        assert_eq!(read_node::<i32>("2 2 0 0\n1 0 0\n").unwrap_err(), ParseError::new(3, 1, ErrorKind::UnexpectedEof));
+        assert_eq!(read_node::<i32>("2 2 0 0\n1 0 0\n3 0 0\n").unwrap_err(), ParseError::new(3, 1, ErrorKind::UnexpectedToken("3".into())));
+        assert_eq!(read_node::<i32>("99999999999999999 2 0 0\n").unwrap_err(), ParseError::new(2, 1, ErrorKind::UnexpectedEof));
+        let err = read_node::<i32>("1 2 18446744073709551615 0\n1 0 0\n").unwrap_err();
+        assert_eq!(err, ParseError::new(1, 5, ErrorKind::InvalidHeader("too many attributes".into())));
+        let err = read_poly::<i32>("1 2\n1 0 0\n99999999999999999 0\n").unwrap_err();
+        assert_eq!(err, ParseError::new(4, 1, ErrorKind::UnexpectedEof));
+    }
+}
@@ -0,0 +1,259 @@
+use crate::{ErrorKind, ParseError};
+use point::Point;
+use segment::LineSegment;
+use std::fmt;
+use std::str::FromStr;
+
+/// The subset of Well-Known Text geometries we read and write.
+/// `Display` produces WKT.
+/// ```
+/// use formats::Geometry;
+/// let g: Geometry<i32> = formats::read_wkt("linestring (0 0, 1 1, 2 0)").unwrap();
+/// assert_eq!(g, Geometry::LineString(vec![[0, 0].into(), [1, 1].into(), [2, 0].into()]));
+/// assert_eq!(g.to_string(), "LINESTRING (0 0, 1 1, 2 0)");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum Geometry<N> {
+    Point(Point<2, N>),
+    LineString(Vec<Point<2, N>>),
+    /// Exterior ring followed by any holes.
+    Polygon(Vec<Vec<Point<2, N>>>),
+}
+
+impl<N> From<LineSegment<2, N>> for Geometry<N> {
+    fn from(s: LineSegment<2, N>) -> Self {
+        Geometry::LineString(vec![s.start, s.end])
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Kind {
+    Word,
+    Open,
+    Close,
+    Comma,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Token<'a> {
+    kind: Kind,
+    text: &'a str,
+    line: usize,
+    column: usize,
+}
+
+impl Token<'_> {
+    fn error(&self, kind: ErrorKind) -> ParseError {
+        ParseError::new(self.line, self.column, kind)
+    }
+
+    fn unexpected(&self) -> ParseError {
+        self.error(ErrorKind::UnexpectedToken(self.text.to_string()))
+    }
+}
+
+fn tokenize(input: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let (mut line, mut column) = (1, 1);
+    let mut word: Option<(usize, usize)> = None;
+    let mut chars = input.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        let kind = match c {
+            '(' => Some(Kind::Open),
+            ')' => Some(Kind::Close),
+            ',' => Some(Kind::Comma),
+            _ => None,
+        };
+        let is_sep = c.is_whitespace() || kind.is_some();
+        if is_sep {
+            if let Some((start, col)) = word.take() {
+                tokens.push(Token { kind: Kind::Word, text: &input[start..i], line, column: col });
+            }
+        } else if word.is_none() {
+            word = Some((i, column));
+        }
+        if let Some(kind) = kind {
+            tokens.push(Token { kind, text: &input[i..i + 1], line, column });
+        }
+        if c == '\n' {
+            line += 1;
+            column = 1;
+        } else {
+            column += 1;
+        }
+        if chars.peek().is_none() {
+            if let Some((start, col)) = word.take() {
+                tokens.push(Token { kind: Kind::Word, text: &input[start..], line, column: col });
+            }
+        }
+    }
+    tokens
+}
+
+struct Parser<'a> {
+    tokens: std::iter::Peekable<std::vec::IntoIter<Token<'a>>>,
+    end: (usize, usize),
+}
+
+impl<'a> Parser<'a> {
+    fn next(&mut self) -> Result<Token<'a>, ParseError> {
+        self.tokens
+            .next()
+            .ok_or(ParseError::new(self.end.0, self.end.1, ErrorKind::UnexpectedEof))
+    }
+
+    fn expect(&mut self, kind: Kind) -> Result<Token<'a>, ParseError> {
+        let t = self.next()?;
+        if t.kind == kind { Ok(t) } else { Err(t.unexpected()) }
+    }
+
+    fn point<N: FromStr>(&mut self) -> Result<Point<2, N>, ParseError> {
+        let mut coord = || -> Result<N, ParseError> {
+            let t = self.expect(Kind::Word)?;
+            t.text
+                .parse()
+                .map_err(|_| t.error(ErrorKind::InvalidNumber(t.text.to_string())))
+        };
+        Ok(Point { coords: [coord()?, coord()?] })
+    }
+
+    /// `EMPTY` or a parenthesized, comma separated list of `item`.
+    fn list<T>(&mut self, mut item: impl FnMut(&mut Self) -> Result<T, ParseError>) -> Result<Vec<T>, ParseError> {
+        let t = self.next()?;
+        match t.kind {
+            Kind::Word if t.text.eq_ignore_ascii_case("EMPTY") => return Ok(Vec::new()),
+            Kind::Open => {}
+            _ => return Err(t.unexpected()),
+        }
+        let mut items = vec![item(self)?];
+        loop {
+            let t = self.next()?;
+            match t.kind {
+                Kind::Comma => items.push(item(self)?),
+                Kind::Close => return Ok(items),
+                _ => return Err(t.unexpected()),
+            }
+        }
+    }
+}
+
+/// Parses a single `POINT`, `LINESTRING` or `POLYGON`. Keywords are case
+/// insensitive and `EMPTY` is accepted for line strings and polygons.
+pub fn read_wkt<N: FromStr>(input: &str) -> Result<Geometry<N>, ParseError> {
+    let end = match input.lines().count() {
+        0 => (1, 1),
+        n => (n, input.lines().last().unwrap().chars().count() + 1),
+    };
+    let mut parser = Parser { tokens: tokenize(input).into_iter().peekable(), end };
+    let tag = parser.expect(Kind::Word)?;
+    let geometry = match tag.text.to_ascii_uppercase().as_str() {
+        "POINT" => {
+            parser.expect(Kind::Open)?;
+            let p = parser.point()?;
+            parser.expect(Kind::Close)?;
+            Geometry::Point(p)
+        }
+        "LINESTRING" => Geometry::LineString(parser.list(Parser::point)?),
+        "POLYGON" => Geometry::Polygon(parser.list(|p| p.list(Parser::point))?),
+        _ => return Err(tag.unexpected()),
+    };
+    match parser.tokens.next() {
+        Some(t) => Err(t.unexpected()),
+        None => Ok(geometry),
+    }
+}
+
+impl<N: FromStr> FromStr for Geometry<N> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        read_wkt(s)
+    }
+}
+
+fn write_ring<N: fmt::Display>(f: &mut fmt::Formatter<'_>, ring: &[Point<2, N>]) -> fmt::Result {
+    if ring.is_empty() {
+        return f.write_str("EMPTY");
+    }
+    f.write_str("(")?;
+    for (i, p) in ring.iter().enumerate() {
+        if i > 0 {
+            f.write_str(", ")?;
+        }
+        write!(f, "{} {}", p.coords[0], p.coords[1])?;
+    }
+    f.write_str(")")
+}
+
+impl<N: fmt::Display> fmt::Display for Geometry<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Geometry::Point(p) => write!(f, "POINT ({} {})", p.coords[0], p.coords[1]),
+            Geometry::LineString(points) => {
+                f.write_str("LINESTRING ")?;
+                write_ring(f, points)
+            }
+            Geometry::Polygon(rings) if rings.is_empty() => f.write_str("POLYGON EMPTY"),
+            Geometry::Polygon(rings) => {
+                f.write_str("POLYGON (")?;
+                for (i, ring) in rings.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write_ring(f, ring)?;
+                }
+                f.write_str(")")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn point() {
+        let g: Geometry<f64> = read_wkt("POINT(1.5 -2)").unwrap();
+        assert_eq!(g, Geometry::Point([1.5, -2.0].into()));
+        assert_eq!(g.to_string(), "POINT (1.5 -2)");
+    }
+
+    #[test]
+    fn polygon_with_hole() {
+        let text = "POLYGON ((0 0, 4 0, 4 4, 0 4, 0 0),\n  (1 1, 1 2, 2 2, 1 1))";
+        let g: Geometry<i32> = text.parse().unwrap();
+        match &g {
+            Geometry::Polygon(rings) => {
+                assert_eq!(rings.len(), 2);
+                assert_eq!(rings[1][2], [2, 2].into());
+            }
+            _ => panic!("expected polygon"),
+        }
+        assert_eq!(g.to_string().parse::<Geometry<i32>>().unwrap(), g);
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(read_wkt::<f64>("LINESTRING EMPTY").unwrap(), Geometry::LineString(vec![]));
+        assert_eq!(read_wkt::<f64>("polygon empty").unwrap().to_string(), "POLYGON EMPTY");
+    }
+
+    #[test]
+    fn from_segment() {
+        let s = LineSegment { start: [0, 1].into(), end: [2, 3].into() };
+        assert_eq!(Geometry::from(s).to_string(), "LINESTRING (0 1, 2 3)");
+    }
+
+    #[test]
+    fn errors() {
+        let err = read_wkt::<f64>("LINESTRING (0 0, 1 x)").unwrap_err();
+        assert_eq!(err, ParseError::new(1, 20, ErrorKind::InvalidNumber("x".into())));
+        let err = read_wkt::<f64>("POLYGON ((0 0, 1 1)\n").unwrap_err();
+        assert_eq!(err.kind, ErrorKind::UnexpectedEof);
+        let err = read_wkt::<f64>("POINT (0 0) (").unwrap_err();
+        assert_eq!(err, ParseError::new(1, 13, ErrorKind::UnexpectedToken("(".into())));
+        let err = read_wkt::<f64>("\n  CIRCLE (0 0)").unwrap_err();
+        assert_eq!(err, ParseError::new(2, 3, ErrorKind::UnexpectedToken("CIRCLE".into())));
+    }
+}
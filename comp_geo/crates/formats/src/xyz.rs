@@ -0,0 +1,132 @@
+use crate::lexer::{Line, Lines};
+use crate::ParseError;
+use point::Point;
+use segment::LineSegment;
+use std::fmt::Display;
+use std::io::{self, Write};
+use std::str::FromStr;
+
+/// Reads one point per line with coordinates separated by whitespace
+/// or commas. Blank lines and `#` comments are skipped, as is a first
+/// line made up entirely of non-numeric fields such as a CSV header.
+/// ```
+/// use point::Point;
+/// let points: Vec<Point<2, f64>> = formats::read_points("x,y\n1.5,2\n-3,4e1\n").unwrap();
+/// assert_eq!(points, vec![[1.5, 2.0].into(), [-3.0, 40.0].into()]);
+/// ```
+pub fn read_points<const D: usize, N: FromStr>(input: &str) -> Result<Vec<Point<D, N>>, ParseError> {
+    let mut lines = Lines::new(input, true).peekable();
+    // Tested as f64 rather than N, so numbers N cannot hold are reported
+    // instead of being skipped as a header
+    if lines
+        .peek()
+        .is_some_and(|line| line.tokens.iter().all(|t| t.parse::<f64>().is_err()))
+    {
+        lines.next();
+    }
+    lines
+        .map(|line| {
+            let p = parse_point(&line, 0)?;
+            line.expect_len(D)?;
+            Ok(p)
+        })
+        .collect()
+}
+
+/// Reads one segment per line as `x1 y1 x2 y2`.
+pub fn read_segments<N: FromStr>(input: &str) -> Result<Vec<LineSegment<2, N>>, ParseError> {
+    Lines::new(input, true)
+        .map(|line| {
+            let start = parse_point(&line, 0)?;
+            let end = parse_point(&line, 2)?;
+            line.expect_len(4)?;
+            Ok(LineSegment { start, end })
+        })
+        .collect()
+}
+
+/// Parses `D` values starting at token `first`.
+pub(crate) fn parse_point<const D: usize, N: FromStr>(line: &Line, first: usize) -> Result<Point<D, N>, ParseError> {
+    let coords = (first..first + D)
+        .map(|i| line.parse(i))
+        .collect::<Result<Vec<N>, _>>()?;
+    Ok(Point { coords: coords.try_into().unwrap_or_else(|_| unreachable!()) })
+}
+
+/// Writes one point per line with coordinates joined by `separator`,
+/// e.g. `" "` for whitespace files or `","` for CSV.
+pub fn write_points<W: Write, const D: usize, N: Display>(
+    w: &mut W,
+    points: &[Point<D, N>],
+    separator: &str,
+) -> io::Result<()> {
+    for p in points {
+        write_coords(w, &p.coords, separator)?;
+        writeln!(w)?;
+    }
+    Ok(())
+}
+
+pub fn write_segments<W: Write, N: Display>(
+    w: &mut W,
+    segments: &[LineSegment<2, N>],
+    separator: &str,
+) -> io::Result<()> {
+    for s in segments {
+        write_coords(w, &s.start.coords, separator)?;
+        write!(w, "{separator}")?;
+        write_coords(w, &s.end.coords, separator)?;
+        writeln!(w)?;
+    }
+    Ok(())
+}
+
+fn write_coords<W: Write, N: Display>(w: &mut W, coords: &[N], separator: &str) -> io::Result<()> {
+    for (i, c) in coords.iter().enumerate() {
+        if i > 0 {
+            write!(w, "{separator}")?;
+        }
+        write!(w, "{c}")?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ErrorKind;
+
+    #[test]
+    fn round_trip() {
+        let points: Vec<Point<3, i32>> = vec![[1, 2, 3].into(), [-4, 5, 6].into()];
+        let mut out = Vec::new();
+        write_points(&mut out, &points, " ").unwrap();
+        let text = String::from_utf8(out).unwrap();
+        assert_eq!(text, "1 2 3\n-4 5 6\n");
+        assert_eq!(read_points::<3, i32>(&text).unwrap(), points);
+    }
+
+    #[test]
+    fn segments() {
+        let segments = vec![LineSegment { start: [0, 0].into(), end: [2, 2].into() }];
+        let mut out = Vec::new();
+        write_segments(&mut out, &segments, ",").unwrap();
+        assert_eq!(out, b"0,0,2,2\n");
+        assert_eq!(read_segments("0 0 2 2").unwrap(), segments);
+        assert_eq!(read_segments::<i32>("0 0 2 2 7").unwrap_err(), ParseError::new(1, 9, ErrorKind::UnexpectedToken("7".into())));
+    }
+
+    #[test]
+    fn errors() {
+        let err = read_points::<2, f64>("1 2\n3 y\n").unwrap_err();
+        assert_eq!(err, ParseError::new(2, 3, ErrorKind::InvalidNumber("y".into())));
+        let err = read_points::<2, f64>("1 2\n3\n").unwrap_err();
+        assert_eq!(err, ParseError::new(2, 2, ErrorKind::MissingValue));
+        let err = read_points::<2, f64>("1 2 3\n").unwrap_err();
+        assert_eq!(err, ParseError::new(1, 5, ErrorKind::UnexpectedToken("3".into())));
+        // Fractions in an integer file are errors, not a header to skip
+        let err = read_points::<2, i32>("1.5 2.5\n").unwrap_err();
+        assert_eq!(err, ParseError::new(1, 1, ErrorKind::InvalidNumber("1.5".into())));
+        assert_eq!(read_points::<2, i32>("x y\n1 2\n").unwrap(), vec![[1, 2].into()]);
+    }
+}
@@ -0,0 +1,370 @@
+use num_traits::Zero;
+use point::{Coordinate, Point};
+use std::cmp::Ordering;
+
+/// The type lengths and areas are reported in. Widths and distances are
+/// irrational in general, so they are given squared. For integer input this
+/// is `Rational128`, which holds them exactly while the polygon spans less
+/// than 2^30 in each coordinate; wider polygons can overflow it and panic
+/// with "Rational overflow".
+pub type Measure<N> = <<N as Coordinate>::Wide as Coordinate>::Field;
+
+/// The narrowest strip holding a convex polygon: one side runs along a
+/// polygon edge and the other through the vertex furthest from it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Strip<N: Coordinate> {
+    pub edge: (Point<2, N>, Point<2, N>),
+    pub opposite: Point<2, N>,
+    pub square_width: Measure<N>,
+}
+
+/// A rectangle enclosing a convex polygon with one side along a polygon
+/// edge.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rectangle<N: Coordinate> {
+    /// Counter-clockwise, with the first two on the line of the edge.
+    pub corners: [Point<2, Measure<N>>; 4],
+    pub area: Measure<N>,
+    pub square_perimeter: Measure<N>,
+}
+
+fn edge<N: Coordinate>(polygon: &[Point<2, N>], i: usize) -> Point<2, N::Wide> {
+    let n = polygon.len();
+    polygon[(i + 1) % n].widen() - polygon[i % n].widen()
+}
+
+fn measure<N: Coordinate>(x: N::Wide) -> Measure<N> {
+    x.to_field()
+}
+
+/// For each edge, the vertices furthest from it, furthest along it and
+/// furthest back along it. Each of the three turns once round the polygon
+/// as the edges do, so all of them together take O(n).
+struct Calipers {
+    edge: usize,
+    far: usize,
+    ahead: usize,
+    behind: usize,
+}
+
+fn calipers<N: Coordinate>(polygon: &[Point<2, N>]) -> Vec<Calipers> {
+    let n = polygon.len();
+    let zero = N::Wide::zero();
+    let (mut far, mut ahead, mut behind) = (1, 1, 0);
+    (0..n)
+        .map(|i| {
+            let d = edge(polygon, i);
+            while d.cross(&edge(polygon, far)) > zero {
+                far += 1;
+            }
+            while d.dot(&edge(polygon, ahead)) > zero {
+                ahead += 1;
+            }
+            if i == 0 {
+                behind = far;
+            }
+            while d.dot(&edge(polygon, behind)) < zero {
+                behind += 1;
+            }
+            Calipers { edge: i, far: far % n, ahead: ahead % n, behind: behind % n }
+        })
+        .collect()
+}
+
+/// The extents of the rectangle along an edge: its height and how far it
+/// reaches ahead of and behind the edge's start, all scaled by the edge's
+/// length, and that length squared.
+struct Extents<M> {
+    height: M,
+    ahead: M,
+    behind: M,
+    square_length: M,
+}
+
+impl<M: Coordinate> Extents<M> {
+    fn area(&self) -> M {
+        (self.ahead - self.behind) * self.height / self.square_length
+    }
+
+    fn square_perimeter(&self) -> M {
+        let half_perimeter = self.ahead - self.behind + self.height;
+        let two = M::one() + M::one();
+        half_perimeter * half_perimeter / self.square_length * two * two
+    }
+}
+
+fn extents<N: Coordinate>(polygon: &[Point<2, N>], c: &Calipers) -> Extents<Measure<N>> {
+    let origin = polygon[c.edge].widen();
+    let d = edge(polygon, c.edge);
+    Extents {
+        height: measure::<N>(d.cross(&(polygon[c.far].widen() - origin))),
+        ahead: measure::<N>(d.dot(&(polygon[c.ahead].widen() - origin))),
+        behind: measure::<N>(d.dot(&(polygon[c.behind].widen() - origin))),
+        square_length: measure::<N>(d.dot(&d)),
+    }
+}
+
+fn rectangle<N: Coordinate>(polygon: &[Point<2, N>], c: &Calipers) -> Rectangle<N> {
+    let origin = polygon[c.edge].widen();
+    let d = edge(polygon, c.edge);
+    let extents = extents(polygon, c);
+    let Extents { height, ahead, behind, square_length } = extents;
+    let zero = Measure::<N>::zero();
+    // The point `along` units of d and `up` units of d turned left from the
+    // origin, both scaled by the square length of d
+    let (dx, dy) = (measure::<N>(d.x()), measure::<N>(d.y()));
+    let corner = |along: Measure<N>, up: Measure<N>| -> Point<2, Measure<N>> {
+        Point::from([
+            measure::<N>(origin.x()) + (along * dx - up * dy) / square_length,
+            measure::<N>(origin.y()) + (along * dy + up * dx) / square_length,
+        ])
+    };
+    Rectangle {
+        corners: [corner(behind, zero), corner(ahead, zero), corner(ahead, height), corner(behind, height)],
+        area: extents.area(),
+        square_perimeter: extents.square_perimeter(),
+    }
+}
+
+/// The calipers whose `key` is least, the first of those if several. Only
+/// the keys are compared, so the corners are worked out once, for the
+/// winner.
+fn least<N: Coordinate>(
+    polygon: &[Point<2, N>],
+    key: impl Fn(&Extents<Measure<N>>) -> Measure<N>,
+) -> Option<Calipers> {
+    calipers(polygon)
+        .into_iter()
+        .map(|c| (key(&extents(polygon, &c)), c))
+        .reduce(|a, b| if b.0 < a.0 { b } else { a })
+        .map(|(_, c)| c)
+}
+
+/// The narrowest strip enclosing `polygon`, or `None` for fewer than two
+/// vertices. O(n).
+/// ```
+/// use hull::width;
+/// use point::{Point, Rational128};
+/// let polygon: Vec<Point<2, i32>> = vec![[0, 0].into(), [4, 0].into(), [4, 1].into(), [0, 3].into()];
+/// let strip = width(&polygon).unwrap();
+/// assert_eq!(strip.square_width, Rational128::new(36, 5));
+/// assert_eq!(strip.edge, ([4, 1].into(), [0, 3].into()));
+/// assert_eq!(strip.opposite, [0, 0].into());
+/// ```
+pub fn width<N: Coordinate>(polygon: &[Point<2, N>]) -> Option<Strip<N>> {
+    if polygon.len() < 2 {
+        return None;
+    }
+    let square_width = |e: &Extents<Measure<N>>| e.height / e.square_length * e.height;
+    let c = least(polygon, square_width)?;
+    Some(Strip {
+        edge: (polygon[c.edge], polygon[(c.edge + 1) % polygon.len()]),
+        opposite: polygon[c.far],
+        square_width: square_width(&extents(polygon, &c)),
+    })
+}
+
+/// The enclosing rectangle of least area, or `None` for fewer than two
+/// vertices. Some such rectangle has a side along a polygon edge, so only
+/// those are tried. O(n).
+/// ```
+/// use hull::min_area_rectangle;
+/// use point::{Point, Rational128};
+/// let diamond: Vec<Point<2, i32>> = vec![[1, 0].into(), [2, 1].into(), [1, 2].into(), [0, 1].into()];
+/// let rectangle = min_area_rectangle(&diamond).unwrap();
+/// assert_eq!(rectangle.area, Rational128::from(2));
+/// assert_eq!(rectangle.corners[0], [Rational128::from(1), Rational128::from(0)].into());
+/// ```
+pub fn min_area_rectangle<N: Coordinate>(polygon: &[Point<2, N>]) -> Option<Rectangle<N>> {
+    if polygon.len() < 2 {
+        return None;
+    }
+    least(polygon, Extents::area).map(|c| rectangle(polygon, &c))
+}
+
+/// The enclosing rectangle of least perimeter, or `None` for fewer than
+/// two vertices. As with area, some such rectangle has a side along a
+/// polygon edge. O(n).
+pub fn min_perimeter_rectangle<N: Coordinate>(polygon: &[Point<2, N>]) -> Option<Rectangle<N>> {
+    if polygon.len() < 2 {
+        return None;
+    }
+    least(polygon, Extents::square_perimeter).map(|c| rectangle(polygon, &c))
+}
+
+/// The index of the lowest vertex, the leftmost of those if several.
+fn bottom<W: Coordinate>(polygon: &[Point<2, W>]) -> usize {
+    let key = |p: &Point<2, W>| [p.y(), p.x()];
+    (0..polygon.len())
+        .min_by(|&i, &j| key(&polygon[i]).partial_cmp(&key(&polygon[j])).unwrap_or(Ordering::Equal))
+        .unwrap()
+}
+
+/// The pairs of vertices from `p` and `q` that meet when a line is turned
+/// around `p` with a parallel line turned around `q` on its far side: the
+/// vertices of the Minkowski sum of `p` and `-q`, merged by edge angle.
+fn antipodal_pairs<N: Coordinate>(p: &[Point<2, N>], q: &[Point<2, N>]) -> Vec<(usize, usize)> {
+    let (n, m) = (p.len(), q.len());
+    if n == 1 || m == 1 {
+        return (0..n).flat_map(|i| (0..m).map(move |j| (i, j))).collect();
+    }
+    let zero = N::Wide::zero();
+    let p: Vec<Point<2, N::Wide>> = p.iter().map(|v| v.widen()).collect();
+    let q: Vec<Point<2, N::Wide>> = q.iter().map(|v| Point::from([zero, zero]) - v.widen()).collect();
+    let (start_p, start_q) = (bottom(&p), bottom(&q));
+    let (mut a, mut b) = (0, 0);
+    let mut pairs = Vec::with_capacity(n + m);
+    while a < n || b < m {
+        pairs.push(((start_p + a) % n, (start_q + b) % m));
+        let turn = if a == n {
+            Ordering::Less
+        } else if b == m {
+            Ordering::Greater
+        } else {
+            let step = |v: &[Point<2, N::Wide>], i: usize| v[(i + 1) % v.len()] - v[i % v.len()];
+            step(&p, start_p + a).cross(&step(&q, start_q + b)).partial_cmp(&zero).unwrap_or(Ordering::Equal)
+        };
+        // The edge turned through less comes first; parallel ones together
+        if turn != Ordering::Less {
+            a += 1;
+        }
+        if turn != Ordering::Greater {
+            b += 1;
+        }
+    }
+    pairs
+}
+
+/// The vertex of `p` and vertex of `q` furthest apart, or `None` if either
+/// is empty. O(n + m).
+/// ```
+/// use hull::max_distance;
+/// use point::Point;
+/// let p: Vec<Point<2, i32>> = vec![[0, 0].into(), [1, 0].into(), [0, 1].into()];
+/// let q: Vec<Point<2, i32>> = vec![[5, 5].into(), [6, 5].into(), [6, 7].into()];
+/// assert_eq!(max_distance(&p, &q), Some(([0, 0].into(), [6, 7].into())));
+/// ```
+pub fn max_distance<N: Coordinate>(p: &[Point<2, N>], q: &[Point<2, N>]) -> Option<(Point<2, N>, Point<2, N>)> {
+    if p.is_empty() || q.is_empty() {
+        return None;
+    }
+    let distance = |&(i, j): &(usize, usize)| crate::square_distance(&p[i], &q[j]);
+    let (i, j) = antipodal_pairs(p, q)
+        .into_iter()
+        .reduce(|a, b| if distance(&b) > distance(&a) { b } else { a })?;
+    Some((p[i], q[j]))
+}
+
+/// The two vertices furthest apart, or `None` for an empty polygon. O(n).
+/// ```
+/// use hull::diameter;
+/// use point::Point;
+/// let polygon: Vec<Point<2, i32>> = vec![[0, 0].into(), [3, 0].into(), [4, 2].into(), [1, 1].into()];
+/// assert_eq!(diameter(&polygon), Some(([0, 0].into(), [4, 2].into())));
+/// ```
+pub fn diameter<N: Coordinate>(polygon: &[Point<2, N>]) -> Option<(Point<2, N>, Point<2, N>)> {
+    max_distance(polygon, polygon)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{monotone_chain, square_distance, Rng};
+    use point::{orientation, Rational128};
+
+    fn random_hulls() -> Vec<Vec<Point<2, i32>>> {
+        let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+        (0..200)
+            .map(|round| {
+                let range = [3, 10, 100, 100_000, i32::MAX / 2][round % 5];
+                let n = 1 + rng.below(40) as usize;
+                let points: Vec<Point<2, i32>> = (0..n).map(|_| [rng.below(range), rng.below(range)].into()).collect();
+                monotone_chain(&points)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn farthest_pairs() {
+        let hulls = random_hulls();
+        for pair in hulls.windows(2) {
+            let (p, q) = (&pair[0], &pair[1]);
+            let brute = p.iter().flat_map(|a| q.iter().map(move |b| square_distance(a, b))).max().unwrap();
+            let (a, b) = max_distance(p, q).unwrap();
+            assert_eq!(square_distance(&a, &b), brute, "{p:?} {q:?}");
+            assert!(p.contains(&a) && q.contains(&b));
+
+            let brute = p.iter().flat_map(|a| p.iter().map(move |b| square_distance(a, b))).max().unwrap();
+            let (a, b) = diameter(p).unwrap();
+            assert_eq!(square_distance(&a, &b), brute, "{p:?}");
+        }
+        assert_eq!(diameter::<i32>(&[]), None);
+        assert_eq!(diameter(&[Point::from([2, 3])]), Some(([2, 3].into(), [2, 3].into())));
+    }
+
+    #[test]
+    fn strips_and_rectangles() {
+        for hull in random_hulls().into_iter().filter(|h| h.len() >= 2) {
+            let field = |p: &Point<2, i32>| p.widen().to_field();
+            let n = hull.len();
+            // Brute force: each edge against every vertex
+            let widths = (0..n).map(|i| {
+                let (a, b) = (hull[i].widen(), hull[(i + 1) % n].widen());
+                let height = hull.iter().map(|v| (b - a).cross(&(v.widen() - a))).max().unwrap();
+                Rational128::from(height as i128 * height as i128) / Rational128::from((b - a).dot(&(b - a)) as i128)
+            });
+            let strip = width(&hull).unwrap();
+            assert_eq!(Some(strip.square_width), widths.min());
+
+            // The corners have denominators near the square edge length, so
+            // crossing them overflows Rational128 on the widest hulls
+            let span = hull.iter().flat_map(|p| [p.x(), p.y()]).max().unwrap();
+            for rectangle in [min_area_rectangle(&hull).unwrap(), min_perimeter_rectangle(&hull).unwrap()] {
+                if span > 100_000 {
+                    continue;
+                }
+                let [a, b, c, d] = rectangle.corners;
+                assert_eq!(rectangle.area, (b - a).cross(&(d - a)));
+                for v in &hull {
+                    for (s, t) in [(a, b), (b, c), (c, d), (d, a)] {
+                        assert_ne!(orientation(&s, &t, &field(v)), Ordering::Less, "{v:?} outside {rectangle:?}");
+                    }
+                }
+            }
+            let area = min_area_rectangle(&hull).unwrap().area;
+            let perimeter = min_perimeter_rectangle(&hull).unwrap().square_perimeter;
+            for c in calipers(&hull) {
+                assert!(extents(&hull, &c).area() >= area);
+                assert!(extents(&hull, &c).square_perimeter() >= perimeter);
+            }
+        }
+    }
+
+    #[test]
+    fn rectangles() {
+        let r = |n: i128| Rational128::from(n);
+        // A 2 by 1 box is its own smallest rectangle both ways
+        let plank: Vec<Point<2, i32>> = vec![[0, 0].into(), [2, 0].into(), [2, 1].into(), [0, 1].into()];
+        let rectangle = min_perimeter_rectangle(&plank).unwrap();
+        assert_eq!((rectangle.area, rectangle.square_perimeter), (r(2), r(36)));
+        // A thin sliver along the diagonal: the best rectangle is tilted
+        let sliver: Vec<Point<2, i32>> = vec![[0, 0].into(), [4, 3].into(), [3, 3].into()];
+        let rectangle = min_area_rectangle(&sliver).unwrap();
+        assert_eq!(rectangle.area, r(3));
+        assert_eq!(rectangle.corners[0], [r(0), r(0)].into());
+        assert_eq!(rectangle.corners[1], [r(4), r(3)].into());
+        let segment: Vec<Point<2, i32>> = vec![[0, 0].into(), [3, 4].into()];
+        assert_eq!(width(&segment).unwrap().square_width, r(0));
+        assert_eq!(min_area_rectangle(&segment).unwrap().square_perimeter, r(100));
+        assert_eq!(min_area_rectangle(&[Point::from([1, 1])]), None);
+    }
+
+    #[test]
+    fn floats() {
+        let polygon: Vec<Point<2, f64>> =
+            vec![[0.0, 0.0].into(), [2.0, 0.0].into(), [2.0, 1.0].into(), [0.0, 1.0].into()];
+        assert_eq!(width(&polygon).unwrap().square_width, 1.0);
+        assert_eq!(min_area_rectangle(&polygon).unwrap().area, 2.0);
+        assert_eq!(diameter(&polygon), Some(([0.0, 0.0].into(), [2.0, 1.0].into())));
+    }
+}
@@ -0,0 +1,97 @@
+use crate::{lex_cmp, monotone_chain, square_distance, wraps_tighter};
+use point::{orientation, Coordinate, Point};
+use std::cmp::Ordering;
+
+/// Chan's algorithm. Splits the points into groups of `m`, takes the hull
+/// of each group with the monotone chain and then gift-wraps the group
+/// hulls, finding the next vertex in each group by binary search. Guessing
+/// `m` by repeated squaring gives O(n log h) for a hull of h vertices.
+pub fn chan<N: Coordinate>(points: &[Point<2, N>]) -> Vec<Point<2, N>> {
+    let n = points.len();
+    let mut m: usize = 4;
+    loop {
+        m = m.saturating_mul(m).min(n.max(1));
+        if let Some(hull) = wrap_groups(points, m) {
+            return hull;
+        }
+    }
+}
+
+/// Gift-wraps hulls of groups of `m` points, giving up once the hull has
+/// more than `m` vertices. A `m` of at least `points.len()` always succeeds.
+fn wrap_groups<N: Coordinate>(points: &[Point<2, N>], m: usize) -> Option<Vec<Point<2, N>>> {
+    let groups: Vec<Vec<Point<2, N>>> = points.chunks(m).map(monotone_chain).collect();
+    // The lowest point of all starts the hull of its group
+    let Some(first) = (0..groups.len()).min_by(|&i, &j| lex_cmp(&groups[i][0], &groups[j][0])) else {
+        return Some(Vec::new());
+    };
+    let start = groups[first][0];
+    let mut hull = vec![start];
+    let (mut p, mut p_at) = (start, (first, 0));
+    for _ in 0..m {
+        let mut next: Option<(Point<2, N>, (usize, usize))> = None;
+        for (g, group) in groups.iter().enumerate() {
+            let i = if g == p_at.0 { (p_at.1 + 1) % group.len() } else { tangent(group, &p) };
+            let q = group[i];
+            if q != p && next.is_none_or(|(best, _)| wraps_tighter(&p, &best, &q)) {
+                next = Some((q, (g, i)));
+            }
+        }
+        let Some((q, q_at)) = next else {
+            return Some(hull);
+        };
+        if q == start {
+            return Some(hull);
+        }
+        hull.push(q);
+        (p, p_at) = (q, q_at);
+    }
+    (m >= points.len()).then_some(hull)
+}
+
+/// Index of the vertex of the convex, counter-clockwise `hull` that every
+/// other vertex is left of or on, as seen from `p` outside it. Of two
+/// vertices in line with `p`, the further one.
+fn tangent<N: Coordinate>(hull: &[Point<2, N>], p: &Point<2, N>) -> usize {
+    let m = hull.len();
+    if m < 3 {
+        let mut best = 0;
+        for i in 1..m {
+            if hull[best] == *p || (hull[i] != *p && wraps_tighter(p, &hull[best], &hull[i])) {
+                best = i;
+            }
+        }
+        return best;
+    }
+    // A repeat of p can only be the tangent's predecessor, which the search
+    // below handles everywhere except at vertex 0
+    if hull[0] == *p {
+        return 1;
+    }
+    // Seen from p, the vertices turn counter-clockwise along the edges that
+    // face away from p and clockwise along the ones facing it. The tangent
+    // starts the first run. Whether a vertex is clockwise of vertex 0 tells
+    // which side of the tangent it is on.
+    let turns_left = |i: usize| orientation(p, &hull[i], &hull[(i + 1) % m]) != Ordering::Less;
+    let from_first = |i: usize| orientation(p, &hull[0], &hull[i]);
+    let at_or_after = |i: usize| {
+        if turns_left(0) {
+            turns_left(i) && from_first(i) == Ordering::Less
+        } else {
+            turns_left(i) || from_first(i) == Ordering::Greater
+        }
+    };
+    let (mut lo, mut hi) = (1, m);
+    while lo < hi {
+        let mid = (lo + hi) / 2;
+        if at_or_after(mid) {
+            hi = mid;
+        } else {
+            lo = mid + 1;
+        }
+    }
+    let i = if turns_left(0) { lo % m } else { lo.min(m - 1) };
+    let j = (i + 1) % m;
+    let in_line = orientation(p, &hull[i], &hull[j]) == Ordering::Equal;
+    if in_line && square_distance(p, &hull[j]) > square_distance(p, &hull[i]) { j } else { i }
+}
@@ -0,0 +1,332 @@
+use crate::{lex_cmp, square_distance};
+use bst::BST;
+use point::{orientation, Coordinate, Point};
+use std::cmp::Ordering;
+use std::ops::Bound;
+
+/// A point ordered lexicographically, as a tree key.
+#[derive(Debug, Clone, Copy)]
+struct Lex<N>(Point<2, N>);
+
+impl<N: Coordinate> PartialEq for Lex<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<N: Coordinate> Eq for Lex<N> {}
+
+impl<N: Coordinate> PartialOrd for Lex<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N: Coordinate> Ord for Lex<N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        lex_cmp(&self.0, &other.0)
+    }
+}
+
+/// The lower or upper half of the hull, from the lowest point to the
+/// highest in lexicographic order.
+#[derive(Debug)]
+struct Chain<N: Coordinate> {
+    vertices: BST<Lex<N>, Point<2, N>>,
+    /// The way the chain turns at each vertex: left for the lower chain,
+    /// right for the upper one.
+    turn: Ordering,
+}
+
+impl<N: Coordinate> Chain<N> {
+    fn new(turn: Ordering) -> Self {
+        Self { vertices: BST::new(), turn }
+    }
+
+    fn has(&self, p: &Point<2, N>) -> bool {
+        self.vertices.find(&Lex(*p)).is_some()
+    }
+
+    fn next(&self, p: &Point<2, N>) -> Option<Point<2, N>> {
+        self.vertices.neighbors(&Lex(*p)).1.copied()
+    }
+
+    fn previous(&self, p: &Point<2, N>) -> Option<Point<2, N>> {
+        self.vertices.neighbors(&Lex(*p)).0.copied()
+    }
+
+    /// Whether `p` is on the chain or on the same side of it as the hull.
+    fn encloses(&self, p: &Point<2, N>) -> bool {
+        if self.has(p) {
+            return true;
+        }
+        match self.vertices.neighbors(&Lex(*p)) {
+            (Some(a), Some(b)) => orientation(a, b, p) != self.turn.reverse(),
+            _ => false,
+        }
+    }
+
+    /// Adds `p` if it lies outside the chain and drops the vertices it
+    /// makes redundant on either side.
+    fn insert(&mut self, p: Point<2, N>) {
+        if self.encloses(&p) {
+            return;
+        }
+        self.vertices.insert(Lex(p), p);
+        while let Some(b) = self.next(&p) {
+            match self.next(&b) {
+                Some(c) if orientation(&p, &b, &c) != self.turn => self.vertices.delete(&Lex(b)),
+                _ => break,
+            }
+        }
+        while let Some(b) = self.previous(&p) {
+            match self.previous(&b) {
+                Some(a) if orientation(&a, &b, &p) != self.turn => self.vertices.delete(&Lex(b)),
+                _ => break,
+            }
+        }
+    }
+
+    /// Removes the vertex `p`, which is no longer in `points`. Vertices
+    /// stay vertices when a point goes, so only the stretch between `p`'s
+    /// neighbours is rebuilt, from the points that lie in it.
+    fn remove(&mut self, p: &Point<2, N>, points: &BST<Lex<N>, usize>) {
+        let (previous, next) = (self.previous(p), self.next(p));
+        self.vertices.delete(&Lex(*p));
+        let bound = |q: Option<Point<2, N>>| q.map_or(Bound::Unbounded, |q| Bound::Excluded(Lex(q)));
+        let between = points.range((bound(previous), bound(next))).map(|(q, _)| q.0);
+        let mut stack: Vec<Point<2, N>> = previous.into_iter().collect();
+        for q in between.chain(next) {
+            while stack.len() >= 2 && orientation(&stack[stack.len() - 2], &stack[stack.len() - 1], &q) != self.turn {
+                stack.pop();
+            }
+            stack.push(q);
+        }
+        for q in stack {
+            if Some(q) != previous && Some(q) != next {
+                self.vertices.insert(Lex(q), q);
+            }
+        }
+    }
+
+    /// Vertices among which the tangents from `p`, outside the hull, are
+    /// found. Split at `p`, each side of the chain turns towards `p` and
+    /// then away from it at most once; the tree is searched for the turn.
+    fn tangent_candidates(&self, p: &Point<2, N>, candidates: &mut Vec<Point<2, N>>) {
+        let key = Lex(*p);
+        let side = |v: &Point<2, N>| self.next(v).map(|w| orientation(p, v, &w));
+        let (Some((_, &first)), Some((_, &last))) = (self.vertices.first(), self.vertices.last()) else {
+            return;
+        };
+        let (before, after) = self.vertices.neighbors(&key);
+        candidates.extend([Some(first), Some(last), before.copied(), after.copied()].into_iter().flatten());
+
+        let left_start = side(&first);
+        let left = self.vertices.partition_point(|k, v| *k < key && side(v) == left_start);
+        let right_start = after.and_then(side);
+        let right = self.vertices.partition_point(|k, v| *k < key || side(v) == right_start);
+        for (_, v) in left.into_iter().chain(right) {
+            candidates.extend([Some(*v), self.previous(v), self.next(v)].into_iter().flatten());
+        }
+    }
+}
+
+/// A convex hull that can gain and lose points. Each half of the hull is
+/// kept in a `BST` keyed in lexicographic order, alongside every point
+/// added so far. Adding a point takes O(log n) time plus the time to drop
+/// the vertices it hides. Removing a point that is not a hull vertex takes
+/// O(log n); removing a vertex rescans the points under the edge it leaves
+/// behind, which is fast for the scattered changes of interactive use but
+/// O(n) at worst. The hull follows the crate's conventions, like
+/// [`monotone_chain`](crate::monotone_chain).
+/// ```
+/// use hull::DynamicHull;
+/// use point::Point;
+/// let mut hull: DynamicHull<i32> = [[0, 0], [4, 0], [4, 4], [0, 4], [1, 2]].into_iter().map(Point::from).collect();
+/// assert!(hull.contains(&[2, 2].into()));
+/// hull.remove(&[4, 4].into());
+/// assert_eq!(hull.hull(), vec![[0, 0].into(), [4, 0].into(), [0, 4].into()]);
+/// assert_eq!(hull.tangents(&[-2, 2].into()), Some(([0, 0].into(), [0, 4].into())));
+/// ```
+#[derive(Debug)]
+pub struct DynamicHull<N: Coordinate> {
+    /// Every point with how many times it has been added.
+    points: BST<Lex<N>, usize>,
+    lower: Chain<N>,
+    upper: Chain<N>,
+    len: usize,
+}
+
+impl<N: Coordinate> Default for DynamicHull<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N: Coordinate> DynamicHull<N> {
+    pub fn new() -> Self {
+        Self { points: BST::new(), lower: Chain::new(Ordering::Greater), upper: Chain::new(Ordering::Less), len: 0 }
+    }
+
+    /// The number of points, counting repeats.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn insert(&mut self, p: Point<2, N>) {
+        let key = Lex(p);
+        let count = self.points.find(&key).copied().unwrap_or(0);
+        if count > 0 {
+            self.points.delete(&key);
+        }
+        self.points.insert(key, count + 1);
+        self.len += 1;
+        self.lower.insert(p);
+        self.upper.insert(p);
+    }
+
+    /// Removes one copy of `p`, returning whether there was one.
+    pub fn remove(&mut self, p: &Point<2, N>) -> bool {
+        let key = Lex(*p);
+        let Some(&count) = self.points.find(&key) else {
+            return false;
+        };
+        self.points.delete(&key);
+        self.len -= 1;
+        if count > 1 {
+            self.points.insert(key, count - 1);
+            return true;
+        }
+        for chain in [&mut self.lower, &mut self.upper] {
+            if chain.has(p) {
+                chain.remove(p, &self.points);
+            }
+        }
+        true
+    }
+
+    /// The hull vertices counter-clockwise from the lowest point.
+    pub fn hull(&self) -> Vec<Point<2, N>> {
+        let mut hull: Vec<Point<2, N>> = self.lower.vertices.iter().map(|(_, p)| *p).collect();
+        let upper: Vec<Point<2, N>> = self.upper.vertices.iter().map(|(_, p)| *p).collect();
+        if upper.len() > 2 {
+            hull.extend(upper[1..upper.len() - 1].iter().rev());
+        }
+        hull
+    }
+
+    /// Whether `p` is inside the hull or on its boundary.
+    pub fn contains(&self, p: &Point<2, N>) -> bool {
+        self.lower.encloses(p) && self.upper.encloses(p)
+    }
+
+    /// The hull vertices touched by the two tangent lines from `p`, or
+    /// `None` if `p` is not outside the hull. The first has the hull to the
+    /// left of the line from `p` through it, the second to the right. Of
+    /// several vertices on a tangent line, the furthest from `p` is taken.
+    /// O(log² n).
+    pub fn tangents(&self, p: &Point<2, N>) -> Option<(Point<2, N>, Point<2, N>)> {
+        if self.is_empty() || self.contains(p) {
+            return None;
+        }
+        let mut candidates = Vec::new();
+        self.lower.tangent_candidates(p, &mut candidates);
+        self.upper.tangent_candidates(p, &mut candidates);
+        // Of two candidates, the one further round in the direction `turn`
+        let extreme = |turn: Ordering| {
+            candidates.iter().copied().reduce(|q, r| {
+                let further = match orientation(p, &q, &r) {
+                    Ordering::Equal => square_distance(p, &r) > square_distance(p, &q),
+                    o => o == turn,
+                };
+                if further { r } else { q }
+            })
+        };
+        let (first, second) = (extreme(Ordering::Less)?, extreme(Ordering::Greater)?);
+        Some((first, second))
+    }
+}
+
+impl<N: Coordinate> FromIterator<Point<2, N>> for DynamicHull<N> {
+    fn from_iter<I: IntoIterator<Item = Point<2, N>>>(iter: I) -> Self {
+        let mut hull = Self::new();
+        for p in iter {
+            hull.insert(p);
+        }
+        hull
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{monotone_chain, Rng};
+
+    /// Tangents by checking every hull vertex.
+    fn brute_tangents(hull: &[Point<2, i32>], p: &Point<2, i32>) -> (Point<2, i32>, Point<2, i32>) {
+        let tangent = |side: Ordering| {
+            let touching = |t: &&Point<2, i32>| hull.iter().all(|q| orientation(p, t, q) != side);
+            *hull.iter().filter(touching).max_by_key(|t| square_distance(p, t)).unwrap()
+        };
+        (tangent(Ordering::Less), tangent(Ordering::Greater))
+    }
+
+    fn on_or_inside(hull: &[Point<2, i32>], q: &Point<2, i32>) -> bool {
+        match hull {
+            [] => false,
+            [p] => p == q,
+            [a, b] => orientation(a, b, q) == Ordering::Equal && lex_cmp(a, q).is_le() && lex_cmp(q, b).is_le(),
+            _ => (0..hull.len()).all(|i| orientation(&hull[i], &hull[(i + 1) % hull.len()], q) != Ordering::Less),
+        }
+    }
+
+    #[test]
+    fn insert_and_remove() {
+        let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+        for round in 0..60 {
+            let range = [3, 6, 30, 1000][round % 4];
+            let mut hull = DynamicHull::new();
+            let mut present: Vec<Point<2, i32>> = Vec::new();
+            for _ in 0..200 {
+                if !present.is_empty() && rng.below(3) == 0 {
+                    let p = present.swap_remove(rng.below(present.len() as i32) as usize);
+                    assert!(hull.remove(&p));
+                } else {
+                    let p: Point<2, i32> = [rng.below(range), rng.below(range)].into();
+                    hull.insert(p);
+                    present.push(p);
+                }
+                let expected = monotone_chain(&present);
+                assert_eq!(hull.hull(), expected, "{present:?}");
+                assert_eq!(hull.len(), present.len());
+
+                let q: Point<2, i32> = [rng.below(range + 4) - 2, rng.below(range + 4) - 2].into();
+                let inside = on_or_inside(&expected, &q);
+                assert_eq!(hull.contains(&q), inside, "{q:?} in {expected:?}");
+                if inside || expected.is_empty() {
+                    assert_eq!(hull.tangents(&q), None);
+                } else {
+                    assert_eq!(hull.tangents(&q), Some(brute_tangents(&expected, &q)), "{q:?} to {expected:?}");
+                }
+            }
+        }
+        assert!(!DynamicHull::<i32>::new().remove(&[0, 0].into()));
+    }
+
+    #[test]
+    fn repeated_points() {
+        let mut hull = DynamicHull::new();
+        for p in [[0, 0], [2, 0], [2, 0], [1, 3]] {
+            hull.insert(p.into());
+        }
+        assert!(hull.remove(&[2, 0].into()));
+        assert_eq!(hull.hull(), vec![[0, 0].into(), [2, 0].into(), [1, 3].into()]);
+        assert!(hull.remove(&[2, 0].into()));
+        assert_eq!(hull.hull(), vec![[0, 0].into(), [1, 3].into()]);
+        assert!(!hull.remove(&[2, 0].into()));
+    }
+}
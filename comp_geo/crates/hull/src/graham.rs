@@ -0,0 +1,39 @@
+use crate::{lex_cmp, sorted_distinct, square_distance};
+use point::{orientation, Coordinate, Point};
+use std::cmp::Ordering;
+
+/// Graham scan. Sorts the points by angle around the bottom-most point and
+/// keeps the left turns. O(n log n).
+pub fn graham_scan<N: Coordinate>(points: &[Point<2, N>]) -> Vec<Point<2, N>> {
+    let mut points = sorted_distinct(points);
+    if points.len() < 3 {
+        return points;
+    }
+    // Every other point is above the pivot or level with it to the right,
+    // so their angles all lie in [0, pi) and orientation orders them
+    let bottom = |a: &Point<2, N>, b: &Point<2, N>| {
+        a.y().partial_cmp(&b.y()).unwrap_or(Ordering::Equal).then(lex_cmp(a, b))
+    };
+    let pivot_index = (0..points.len()).min_by(|&i, &j| bottom(&points[i], &points[j])).unwrap();
+    let pivot = points.swap_remove(pivot_index);
+    let distance = |p: &Point<2, N>| square_distance(&pivot, p);
+    points.sort_by(|a, b| {
+        orientation(&pivot, b, a).then_with(|| distance(a).partial_cmp(&distance(b)).unwrap_or(Ordering::Equal))
+    });
+
+    let mut hull = vec![pivot];
+    for p in points {
+        // Popping on collinear points keeps only the furthest along each edge
+        while hull.len() >= 2 {
+            let k = hull.len();
+            if orientation(&hull[k - 2], &hull[k - 1], &p) == Ordering::Greater {
+                break;
+            }
+            hull.pop();
+        }
+        hull.push(p);
+    }
+    let start = (0..hull.len()).min_by(|&i, &j| lex_cmp(&hull[i], &hull[j])).unwrap();
+    hull.rotate_left(start);
+    hull
+}
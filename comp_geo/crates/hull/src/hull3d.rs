@@ -0,0 +1,419 @@
+use crate::{monotone_chain, Rng};
+use num_traits::Zero;
+use point::{Coordinate, Point};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+/// The convex hull of a set of 3D points. Indices refer to the input
+/// slice; repeated points are represented by their first occurrence.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Hull3 {
+    Empty,
+    /// Every point is the same.
+    Point(usize),
+    /// Every point lies on one line; the two ends.
+    Segment(usize, usize),
+    /// Every point lies in one plane; the corners of the hull polygon in
+    /// order around it.
+    Flat(Vec<usize>),
+    Solid(Polyhedron),
+}
+
+/// A closed triangle mesh. Its vertices are exactly the corners of the
+/// hull: points inside a face or on an edge are left out, and a flat face
+/// with more than three corners is split into coplanar triangles.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polyhedron {
+    /// Input indices of the corners, in increasing order.
+    pub vertices: Vec<usize>,
+    /// Triangles of input indices, counter-clockwise seen from outside.
+    pub faces: Vec<[usize; 3]>,
+    /// `neighbors[f][i]` is the face sharing the edge from `faces[f][i]`
+    /// to `faces[f][(i + 1) % 3]`.
+    pub neighbors: Vec<[usize; 3]>,
+}
+
+impl Polyhedron {
+    /// Every edge once, as input indices `[a, b]` with `a < b`, sorted.
+    pub fn edges(&self) -> Vec<[usize; 2]> {
+        let mut edges: Vec<[usize; 2]> = self
+            .faces
+            .iter()
+            .flat_map(|f| (0..3).map(move |i| [f[i], f[(i + 1) % 3]]))
+            .filter(|[a, b]| a < b)
+            .collect();
+        edges.sort_unstable();
+        edges
+    }
+
+    /// `V - E + F`, which is 2 for any closed triangulated sphere.
+    pub fn euler_characteristic(&self) -> i64 {
+        self.vertices.len() as i64 - self.edges().len() as i64 + self.faces.len() as i64
+    }
+}
+
+/// Sign of the volume of the tetrahedron `a, b, c, d`: `Greater` when `d`
+/// is on the side of the triangle `a, b, c` it is counter-clockwise from.
+/// Works in the wide type of the wide type, so degree three products of
+/// `i32` coordinates are exact.
+fn orientation_3d<N: Coordinate>(a: &Point<3, N>, b: &Point<3, N>, c: &Point<3, N>, d: &Point<3, N>) -> Ordering {
+    let a = a.widen().widen();
+    let (ab, ac, ad) = (b.widen().widen() - a, c.widen().widen() - a, d.widen().widen() - a);
+    ab.cross(&ac).dot(&ad).partial_cmp(&Zero::zero()).unwrap_or(Ordering::Equal)
+}
+
+/// [`orientation_3d`] of the point with index `p` against a face of indices.
+fn side<N: Coordinate>(points: &[Point<3, N>], face: &[usize; 3], p: usize) -> Ordering {
+    orientation_3d(&points[face[0]], &points[face[1]], &points[face[2]], &points[p])
+}
+
+fn collinear<N: Coordinate>(a: &Point<3, N>, b: &Point<3, N>, c: &Point<3, N>) -> bool {
+    let a = a.widen();
+    let n = (b.widen() - a).cross(&(c.widen() - a));
+    n.coords.iter().all(|c| c.is_zero())
+}
+
+/// 3D convex hull by randomized incremental construction with a conflict
+/// graph, in expected O(n log n). Each face keeps the points that can see
+/// it and each point the faces it can see, so adding a point finds the
+/// faces to remove without searching. The insertion order is shuffled with
+/// a fixed seed, so results are reproducible. Exact for `i32` coordinates
+/// of magnitude below 2^30, where the collinearity checks' products fit the
+/// wide type.
+/// ```
+/// use hull::{convex_hull_3d, Hull3};
+/// use point::Point;
+/// let mut points: Vec<Point<3, i32>> = Vec::new();
+/// for x in 0..3 {
+///     for y in 0..3 {
+///         for z in 0..3 {
+///             points.push([x, y, z].into());
+///         }
+///     }
+/// }
+/// let Hull3::Solid(cube) = convex_hull_3d(&points) else { panic!() };
+/// assert_eq!(cube.vertices.len(), 8);
+/// assert_eq!(cube.faces.len(), 12);
+/// assert_eq!(cube.edges().len(), 18);
+/// ```
+pub fn convex_hull_3d<N: Coordinate>(points: &[Point<3, N>]) -> Hull3 {
+    let mut distinct: Vec<usize> = (0..points.len()).collect();
+    let lex = |i: &usize, j: &usize| points[*i].coords.partial_cmp(&points[*j].coords).unwrap_or(Ordering::Equal);
+    distinct.sort_by(|i, j| lex(i, j).then(i.cmp(j)));
+    distinct.dedup_by(|j, i| points[*i] == points[*j]);
+
+    let Some(&first) = distinct.first() else {
+        return Hull3::Empty;
+    };
+    let Some(&second) = distinct.get(1) else {
+        return Hull3::Point(first);
+    };
+    let Some(&third) = distinct.iter().find(|&&i| !collinear(&points[first], &points[second], &points[i])) else {
+        // Sorted order along a line is order along it
+        return Hull3::Segment(first, *distinct.last().unwrap());
+    };
+    let (a, b, c) = (&points[first], &points[second], &points[third]);
+    if !distinct.iter().any(|&i| orientation_3d(a, b, c, &points[i]).is_ne()) {
+        return Hull3::Flat(flat_hull(points, &distinct, a, b, c));
+    }
+
+    let hull = build(points, &distinct);
+    // Points in a face or on an edge can be left over from before the
+    // points around them were added; building again from the corners
+    // alone drops them
+    let corners = corners(points, &hull);
+    if corners.len() == hull.vertices.len() {
+        Hull3::Solid(hull)
+    } else {
+        Hull3::Solid(build(points, &corners))
+    }
+}
+
+/// The hull of coplanar points, found in 2D by dropping the coordinate
+/// along which the plane's normal is longest.
+fn flat_hull<N: Coordinate>(
+    points: &[Point<3, N>],
+    distinct: &[usize],
+    a: &Point<3, N>,
+    b: &Point<3, N>,
+    c: &Point<3, N>,
+) -> Vec<usize> {
+    let a_wide = a.widen();
+    let normal = (b.widen() - a_wide).cross(&(c.widen() - a_wide));
+    let abs = |x: N::Wide| if x < N::Wide::zero() { N::Wide::zero() - x } else { x };
+    let axis = (0..3)
+        .max_by(|&i, &j| abs(normal.coords[i]).partial_cmp(&abs(normal.coords[j])).unwrap_or(Ordering::Equal))
+        .unwrap();
+    let (u, v) = ((axis + 1) % 3, (axis + 2) % 3);
+    let mut projected: Vec<(Point<2, N>, usize)> =
+        distinct.iter().map(|&i| (Point::from([points[i].coords[u], points[i].coords[v]]), i)).collect();
+    projected.sort_by(|p, q| p.0.coords.partial_cmp(&q.0.coords).unwrap_or(Ordering::Equal));
+    let corners: Vec<Point<2, N>> = projected.iter().map(|(p, _)| *p).collect();
+    monotone_chain(&corners)
+        .iter()
+        .map(|p| {
+            let k = projected.partition_point(|(q, _)| q.coords.partial_cmp(&p.coords) == Some(Ordering::Less));
+            projected[k].1
+        })
+        .collect()
+}
+
+/// The hull vertices that are corners: their faces lie in at least three
+/// different planes. The others are inside a face, on an edge or, once
+/// buried by later points, on no face at all. The faces around each
+/// vertex are gathered in one pass, so this takes O(h).
+fn corners<N: Coordinate>(points: &[Point<3, N>], hull: &Polyhedron) -> Vec<usize> {
+    let mut around: HashMap<usize, Vec<[usize; 3]>> = HashMap::new();
+    for face in &hull.faces {
+        for &v in face {
+            around.entry(v).or_default().push(*face);
+        }
+    }
+    hull.vertices.iter().copied().filter(|v| around.get(v).is_some_and(|faces| is_corner(points, faces))).collect()
+}
+
+/// Whether `faces`, all around one vertex, lie in at least three planes.
+fn is_corner<N: Coordinate>(points: &[Point<3, N>], faces: &[[usize; 3]]) -> bool {
+    let mut planes: Vec<[usize; 3]> = Vec::new();
+    for face in faces {
+        let same_plane = |plane: &[usize; 3]| {
+            face.iter().all(|&q| side(points, plane, q).is_eq())
+        };
+        if !planes.iter().any(same_plane) {
+            planes.push(*face);
+            if planes.len() == 3 {
+                return true;
+            }
+        }
+    }
+    false
+}
+
+struct Face {
+    vertices: [usize; 3],
+    neighbors: [usize; 3],
+    /// Points not yet added that see this face.
+    conflicts: Vec<usize>,
+    alive: bool,
+}
+
+/// Incremental construction over the distinct points `order`, which must
+/// not all lie in one plane.
+fn build<N: Coordinate>(points: &[Point<3, N>], order: &[usize]) -> Polyhedron {
+    let above = |f: &[usize; 3], p: usize| side(points, f, p).is_gt();
+
+    // Start from a tetrahedron
+    let p0 = order[0];
+    let p1 = order[1];
+    let p2 = *order.iter().find(|&&i| !collinear(&points[p0], &points[p1], &points[i])).unwrap();
+    let p3 = *order.iter().find(|&&i| side(points, &[p0, p1, p2], i).is_ne()).unwrap();
+    let (p1, p2) = if above(&[p0, p1, p2], p3) { (p1, p2) } else { (p2, p1) };
+    let mut faces: Vec<Face> = [[p0, p2, p1], [p0, p1, p3], [p1, p2, p3], [p2, p0, p3]]
+        .into_iter()
+        .map(|vertices| Face { vertices, neighbors: [0; 3], conflicts: Vec::new(), alive: true })
+        .collect();
+    let mut by_edge = HashMap::new();
+    for (f, face) in faces.iter().enumerate() {
+        for i in 0..3 {
+            by_edge.insert((face.vertices[i], face.vertices[(i + 1) % 3]), f);
+        }
+    }
+    for face in &mut faces {
+        for i in 0..3 {
+            face.neighbors[i] = by_edge[&(face.vertices[(i + 1) % 3], face.vertices[i])];
+        }
+    }
+
+    let mut rest: Vec<usize> = order.iter().copied().filter(|i| ![p0, p1, p2, p3].contains(i)).collect();
+    shuffle(&mut rest);
+    let mut sees: HashMap<usize, Vec<usize>> = HashMap::new();
+    for &p in &rest {
+        for (f, face) in faces.iter_mut().enumerate() {
+            if above(&face.vertices, p) {
+                face.conflicts.push(p);
+                sees.entry(p).or_default().push(f);
+            }
+        }
+    }
+
+    let mut vertices = vec![p0, p1, p2, p3];
+    let mut visible = vec![false; faces.len()];
+    let mut seen = HashMap::new();
+    for &p in &rest {
+        let Some(mut seen_faces) = sees.remove(&p) else {
+            continue;
+        };
+        seen_faces.retain(|&f| faces[f].alive);
+        if seen_faces.is_empty() {
+            continue;
+        }
+        vertices.push(p);
+        for &f in &seen_faces {
+            visible[f] = true;
+        }
+
+        // Edges between a visible face and a hidden one bound the region
+        // to replace; each gets a new face joining it to p
+        let mut new_by_start = HashMap::new();
+        for &f in &seen_faces {
+            for i in 0..3 {
+                let outside = faces[f].neighbors[i];
+                if visible[outside] {
+                    continue;
+                }
+                let (a, b) = (faces[f].vertices[i], faces[f].vertices[(i + 1) % 3]);
+                let id = faces.len();
+                let j = (0..3).find(|&j| faces[outside].vertices[j] == b).unwrap();
+                faces[outside].neighbors[j] = id;
+
+                // Points that might see the new face saw one of the two old ones
+                let mut conflicts = Vec::new();
+                for &q in faces[f].conflicts.iter().chain(&faces[outside].conflicts) {
+                    if q != p && seen.insert(q, id).is_none_or(|last| last != id) && above(&[a, b, p], q) {
+                        conflicts.push(q);
+                        sees.entry(q).or_default().push(id);
+                    }
+                }
+                faces.push(Face { vertices: [a, b, p], neighbors: [outside, 0, 0], conflicts, alive: true });
+                visible.push(false);
+                new_by_start.insert(a, id);
+            }
+        }
+        // Around p, each new face is followed by the one starting where it ends
+        for &id in new_by_start.values() {
+            let next = new_by_start[&faces[id].vertices[1]];
+            faces[id].neighbors[1] = next;
+            faces[next].neighbors[2] = id;
+        }
+        for &f in &seen_faces {
+            faces[f].alive = false;
+            faces[f].conflicts = Vec::new();
+        }
+    }
+
+    // Renumber the surviving faces
+    let mut index = vec![usize::MAX; faces.len()];
+    for (new, f) in (0..faces.len()).filter(|&f| faces[f].alive).enumerate() {
+        index[f] = new;
+    }
+    let alive = faces.iter().filter(|f| f.alive);
+    vertices.sort_unstable();
+    Polyhedron {
+        vertices,
+        faces: alive.clone().map(|f| f.vertices).collect(),
+        neighbors: alive.map(|f| f.neighbors.map(|n| index[n])).collect(),
+    }
+}
+
+/// Fisher–Yates with a fixed seed.
+fn shuffle<T>(items: &mut [T]) {
+    let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+    for i in (1..items.len()).rev() {
+        items.swap(i, (rng.next() % (i as u64 + 1)) as usize);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn solid(points: &[Point<3, i32>]) -> Polyhedron {
+        match convex_hull_3d(points) {
+            Hull3::Solid(hull) => hull,
+            other => panic!("expected a solid hull, got {other:?}"),
+        }
+    }
+
+    /// Checks adjacency, orientation, Euler's formula and that no point is
+    /// outside any face.
+    fn assert_valid(hull: &Polyhedron, points: &[Point<3, i32>]) {
+        assert_eq!(hull.euler_characteristic(), 2);
+        assert_eq!(2 * hull.edges().len(), 3 * hull.faces.len());
+        for (f, face) in hull.faces.iter().enumerate() {
+            for i in 0..3 {
+                let g = hull.neighbors[f][i];
+                let (a, b) = (face[i], face[(i + 1) % 3]);
+                let j = (0..3).find(|&j| hull.faces[g][j] == b).unwrap();
+                assert_eq!(hull.faces[g][(j + 1) % 3], a);
+                assert_eq!(hull.neighbors[g][j], f);
+            }
+            let [a, b, c] = face.map(|i| points[i]);
+            assert!(!collinear(&a, &b, &c));
+            assert!(points.iter().all(|p| orientation_3d(&a, &b, &c, p) != Ordering::Greater));
+        }
+    }
+
+    #[test]
+    fn cube_with_points_on_faces_and_edges() {
+        let mut points: Vec<Point<3, i32>> = Vec::new();
+        for x in 0..=4 {
+            for y in 0..=4 {
+                for z in 0..=4 {
+                    points.push([x, y, z].into());
+                }
+            }
+        }
+        points.extend(points.clone());
+        let hull = solid(&points);
+        assert_valid(&hull, &points);
+        assert_eq!(hull.vertices.len(), 8);
+        assert_eq!(hull.faces.len(), 12);
+        assert!(hull.vertices.iter().all(|&v| points[v].coords.iter().all(|&c| c == 0 || c == 4)));
+    }
+
+    #[test]
+    fn tetrahedron() {
+        let points: Vec<Point<3, i32>> = vec![[0, 0, 0].into(), [1, 0, 0].into(), [0, 1, 0].into(), [0, 0, 1].into()];
+        let hull = solid(&points);
+        assert_valid(&hull, &points);
+        assert_eq!(hull.vertices, vec![0, 1, 2, 3]);
+        assert_eq!(hull.edges().len(), 6);
+    }
+
+    #[test]
+    fn random_points() {
+        let mut rng = Rng(0x1234_5678_9abc_def1);
+        for round in 0..100 {
+            let range = [3, 4, 20, 1000][round % 4];
+            let n = 5 + rng.below(150) as usize;
+            let points: Vec<Point<3, i32>> = (0..n).map(|_| [rng.below(range), rng.below(range), rng.below(range)].into()).collect();
+            if let Hull3::Solid(hull) = convex_hull_3d(&points) {
+                assert_valid(&hull, &points);
+                // Every corner is a true corner of the point set
+                assert_eq!(corners(&points, &hull), hull.vertices);
+            }
+        }
+    }
+
+    #[test]
+    fn degenerate_inputs() {
+        assert_eq!(convex_hull_3d::<i32>(&[]), Hull3::Empty);
+        let same: Vec<Point<3, i32>> = vec![[1, 2, 3].into(); 3];
+        assert_eq!(convex_hull_3d(&same), Hull3::Point(0));
+        let line: Vec<Point<3, i32>> = (0..5).map(|i| Point::from([2 - i, i, 2 * i])).collect();
+        assert_eq!(convex_hull_3d(&line), Hull3::Segment(4, 0));
+
+        // A grid in the plane x + y + z = 6
+        let mut flat: Vec<Point<3, i32>> = Vec::new();
+        for x in 0..=3 {
+            for y in 0..=3 {
+                flat.push([x, y, 6 - x - y].into());
+            }
+        }
+        let Hull3::Flat(corners) = convex_hull_3d(&flat) else { panic!("expected a flat hull") };
+        let mut sorted = corners.clone();
+        sorted.sort_unstable();
+        assert_eq!(sorted, vec![0, 3, 12, 15]);
+    }
+
+    #[test]
+    fn floats() {
+        let points: Vec<Point<3, f64>> = [[0.0, 0.0, 0.0], [2.0, 0.0, 0.0], [0.0, 2.0, 0.0], [0.0, 0.0, 2.0], [0.5; 3]]
+            .into_iter()
+            .map(Point::from)
+            .collect();
+        let Hull3::Solid(hull) = convex_hull_3d(&points) else { panic!("expected a solid hull") };
+        assert_eq!(hull.vertices, vec![0, 1, 2, 3]);
+        assert_eq!(hull.euler_characteristic(), 2);
+    }
+}
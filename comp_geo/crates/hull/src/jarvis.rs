@@ -0,0 +1,28 @@
+use crate::{lowest, wraps_tighter};
+use point::{Coordinate, Point};
+
+/// Jarvis march (gift wrapping). Starting from the lowest point, each
+/// step scans every point for the next hull vertex. O(nh) for a hull of
+/// h vertices, so fast when the hull is small.
+pub fn jarvis_march<N: Coordinate>(points: &[Point<2, N>]) -> Vec<Point<2, N>> {
+    let Some(start) = lowest(points) else {
+        return Vec::new();
+    };
+    let mut hull = vec![start];
+    let mut p = start;
+    // A hull cannot have more vertices than there are points
+    for _ in 0..points.len() {
+        let mut q = p;
+        for r in points {
+            if *r != p && (q == p || wraps_tighter(&p, &q, r)) {
+                q = *r;
+            }
+        }
+        if q == start || q == p {
+            break;
+        }
+        hull.push(q);
+        p = q;
+    }
+    hull
+}
@@ -0,0 +1,193 @@
+//! Convex hulls of planar point sets, and of point clouds in space with
+//! [`convex_hull_3d`].
+//!
+//! Every planar algorithm returns the same hull: its vertices in counter-clockwise
+//! order, starting from the lowest point in lexicographic order (smallest
+//! `x`, then smallest `y`). Only corners are kept, so points in the middle
+//! of a hull edge and repeated points are left out. A set of collinear
+//! points gives its two endpoints and a single distinct point gives itself.
+//! Orientation tests are those of [`point::orientation`], exact for `i32`
+//! coordinates of magnitude below 2^30 and `i64` below 2^62.
+//!
+//! The rotating calipers functions ([`diameter`], [`width`] and so on)
+//! take polygons in the same form: counter-clockwise, with no repeated or
+//! collinear vertices. They compare cross and dot products rather than
+//! lengths, so they are exact within the same bounds; the lengths and areas
+//! they report hold exactly while the polygon spans less than 2^30 (see
+//! [`Measure`]).
+//! ```
+//! use point::Point;
+//! let points: Vec<Point<2, i32>> = vec![[0, 0].into(), [2, 0].into(), [4, 0].into(), [1, 1].into(), [2, 3].into()];
+//! let hull = hull::monotone_chain(&points);
+//! assert_eq!(hull, vec![[0, 0].into(), [4, 0].into(), [2, 3].into()]);
+//! assert_eq!(hull::chan(&points), hull);
+//! ```
+use point::{orientation, Coordinate, Point};
+use std::cmp::Ordering;
+
+mod calipers;
+mod chan;
+mod dynamic;
+mod graham;
+mod hull3d;
+mod jarvis;
+mod monotone;
+mod quickhull;
+
+pub use calipers::{
+    diameter, max_distance, min_area_rectangle, min_perimeter_rectangle, width, Measure, Rectangle, Strip,
+};
+pub use chan::chan;
+pub use dynamic::DynamicHull;
+pub use graham::graham_scan;
+pub use hull3d::{convex_hull_3d, Hull3, Polyhedron};
+pub use jarvis::jarvis_march;
+pub use monotone::monotone_chain;
+pub use quickhull::quickhull;
+
+/// The convex hull by Andrew's monotone chain.
+pub fn convex_hull<N: Coordinate>(points: &[Point<2, N>]) -> Vec<Point<2, N>> {
+    monotone_chain(points)
+}
+
+fn lex_cmp<N: Coordinate>(a: &Point<2, N>, b: &Point<2, N>) -> Ordering {
+    a.coords.partial_cmp(&b.coords).unwrap_or(Ordering::Equal)
+}
+
+/// The points sorted lexicographically without repeats.
+fn sorted_distinct<N: Coordinate>(points: &[Point<2, N>]) -> Vec<Point<2, N>> {
+    let mut points = points.to_vec();
+    points.sort_by(lex_cmp);
+    points.dedup();
+    points
+}
+
+fn lowest<N: Coordinate>(points: &[Point<2, N>]) -> Option<Point<2, N>> {
+    points.iter().copied().min_by(lex_cmp)
+}
+
+fn square_distance<N: Coordinate>(a: &Point<2, N>, b: &Point<2, N>) -> N::Wide {
+    let d = b.widen() - a.widen();
+    d.dot(&d)
+}
+
+/// xorshift64, for the fixed-seed shuffle in `convex_hull_3d` and the
+/// tests' random inputs, so both are reproducible without a dependency on
+/// `rand`.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    #[cfg(test)]
+    fn below(&mut self, n: i32) -> i32 {
+        (self.next() % n as u64) as i32
+    }
+}
+
+/// Whether `r` is a better next hull vertex after `p` than `q` when
+/// wrapping counter-clockwise: further clockwise as seen from `p`, or in
+/// the same direction and further away.
+fn wraps_tighter<N: Coordinate>(p: &Point<2, N>, q: &Point<2, N>, r: &Point<2, N>) -> bool {
+    match orientation(p, q, r) {
+        Ordering::Less => true,
+        Ordering::Equal => square_distance(p, r) > square_distance(p, q),
+        Ordering::Greater => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Algorithm = fn(&[Point<2, i32>]) -> Vec<Point<2, i32>>;
+
+    const ALGORITHMS: [(&str, Algorithm); 5] = [
+        ("monotone_chain", monotone_chain),
+        ("graham_scan", graham_scan),
+        ("jarvis_march", jarvis_march),
+        ("quickhull", quickhull),
+        ("chan", chan),
+    ];
+
+    fn points(p: &[[i32; 2]]) -> Vec<Point<2, i32>> {
+        p.iter().map(|&p| p.into()).collect()
+    }
+
+    /// Checks `hull` is strictly convex, counter-clockwise, starts at the
+    /// lowest point and has every point inside or on it.
+    fn assert_hull_of(hull: &[Point<2, i32>], points: &[Point<2, i32>]) {
+        assert_eq!(hull.first().copied(), lowest(points));
+        let n = hull.len();
+        if n < 3 {
+            assert!(points.iter().all(|p| n < 2 || orientation(&hull[0], &hull[1], p) == Ordering::Equal));
+            return;
+        }
+        for i in 0..n {
+            let (a, b) = (hull[i], hull[(i + 1) % n]);
+            assert_eq!(orientation(&a, &b, &hull[(i + 2) % n]), Ordering::Greater);
+            assert!(points.iter().all(|p| orientation(&a, &b, p) != Ordering::Less));
+        }
+    }
+
+    #[test]
+    fn small_cases() {
+        for (name, algorithm) in ALGORITHMS {
+            assert_eq!(algorithm(&[]), vec![], "{name}");
+            assert_eq!(algorithm(&points(&[[3, 4], [3, 4]])), points(&[[3, 4]]), "{name}");
+            let line = points(&[[2, 2], [0, 0], [3, 3], [1, 1], [3, 3]]);
+            assert_eq!(algorithm(&line), points(&[[0, 0], [3, 3]]), "{name}");
+            let vertical = points(&[[0, 5], [0, 1], [0, 3]]);
+            assert_eq!(algorithm(&vertical), points(&[[0, 1], [0, 5]]), "{name}");
+            // Edge midpoints and the centre are dropped
+            let grid: Vec<_> = (0..3).flat_map(|x| (0..3).map(move |y| Point::from([x, y]))).collect();
+            assert_eq!(algorithm(&grid), points(&[[0, 0], [2, 0], [2, 2], [0, 2]]), "{name}");
+        }
+    }
+
+    #[test]
+    fn random_cross_check() {
+        let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+        for round in 0..300 {
+            let n = 1 + rng.below(200) as usize;
+            // Small ranges give many duplicates and collinear points
+            let range = [3, 10, 50, 1000][round % 4];
+            let points: Vec<Point<2, i32>> = (0..n).map(|_| [rng.below(range), rng.below(range)].into()).collect();
+            let expected = monotone_chain(&points);
+            assert_hull_of(&expected, &points);
+            for (name, algorithm) in &ALGORITHMS[1..] {
+                assert_eq!(algorithm(&points), expected, "{name} on {points:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn points_on_a_circle() {
+        // Every point is a hull vertex, the worst case for output sensitivity
+        let circle = points(&[
+            [5, 0], [4, 3], [3, 4], [0, 5], [-3, 4], [-4, 3], [-5, 0], [-4, -3], [-3, -4], [0, -5], [3, -4], [4, -3],
+        ]);
+        for (name, algorithm) in ALGORITHMS {
+            let hull = algorithm(&circle);
+            assert_eq!(hull.len(), circle.len(), "{name}");
+            assert_eq!(hull[0], [-5, 0].into(), "{name}");
+        }
+    }
+
+    #[test]
+    fn floats() {
+        let points: Vec<Point<2, f64>> =
+            vec![[0.5, 0.5].into(), [0.0, 0.0].into(), [1.0, 0.0].into(), [0.0, 1.0].into(), [0.25, 0.75].into()];
+        let expected: Vec<Point<2, f64>> = vec![[0.0, 0.0].into(), [1.0, 0.0].into(), [0.0, 1.0].into()];
+        assert_eq!(monotone_chain(&points), expected);
+        assert_eq!(graham_scan(&points), expected);
+        assert_eq!(jarvis_march(&points), expected);
+        assert_eq!(quickhull(&points), expected);
+        assert_eq!(chan(&points), expected);
+    }
+}
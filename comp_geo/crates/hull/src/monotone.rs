@@ -0,0 +1,30 @@
+use crate::sorted_distinct;
+use point::{orientation, Coordinate, Point};
+use std::cmp::Ordering;
+
+/// Andrew's monotone chain. Sorts the points and builds the lower and
+/// upper hulls in one pass each. O(n log n).
+pub fn monotone_chain<N: Coordinate>(points: &[Point<2, N>]) -> Vec<Point<2, N>> {
+    let points = sorted_distinct(points);
+    if points.len() < 3 {
+        return points;
+    }
+    let mut hull: Vec<Point<2, N>> = Vec::with_capacity(points.len() + 1);
+    // Lower hull left to right, then upper hull right to left
+    for pass in [points.iter().collect::<Vec<_>>(), points.iter().rev().collect()] {
+        let floor = hull.len();
+        for &p in pass {
+            while hull.len() >= floor + 2 {
+                let k = hull.len();
+                if orientation(&hull[k - 2], &hull[k - 1], &p) == Ordering::Greater {
+                    break;
+                }
+                hull.pop();
+            }
+            hull.push(p);
+        }
+        // The last point of each chain starts the next one
+        hull.pop();
+    }
+    hull
+}
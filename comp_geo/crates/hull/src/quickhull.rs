@@ -0,0 +1,63 @@
+use crate::lex_cmp;
+use num_traits::Zero;
+use point::{Coordinate, Point};
+use std::cmp::Ordering;
+
+/// Twice the signed area of `a`, `b`, `p`: positive when `p` is left of
+/// `a -> b`, and proportional to its distance from the line.
+fn signed_area<N: Coordinate>(a: &Point<2, N>, b: &Point<2, N>, p: &Point<2, N>) -> N::Wide {
+    let a = a.widen();
+    (b.widen() - a).cross(&(p.widen() - a))
+}
+
+enum Step<N> {
+    /// Find the hull vertices strictly right of `a -> b` among `points`.
+    Split(Point<2, N>, Point<2, N>, Vec<Point<2, N>>),
+    Emit(Point<2, N>),
+}
+
+/// QuickHull. Splits the points by the line through the leftmost and
+/// rightmost points and recursively adds the point furthest from each new
+/// edge. O(n log n) on typical inputs and O(n^2) at worst.
+pub fn quickhull<N: Coordinate>(points: &[Point<2, N>]) -> Vec<Point<2, N>> {
+    let (Some(&a), Some(&b)) = (points.iter().min_by(|p, q| lex_cmp(p, q)), points.iter().max_by(|p, q| lex_cmp(p, q)))
+    else {
+        return Vec::new();
+    };
+    if a == b {
+        return vec![a];
+    }
+    let right_of = |a: Point<2, N>, b: Point<2, N>, points: &[Point<2, N>]| -> Vec<Point<2, N>> {
+        points.iter().copied().filter(|p| signed_area(&a, &b, p) < N::Wide::zero()).collect()
+    };
+
+    let mut hull = Vec::new();
+    // An explicit stack, as the recursion can be as deep as the hull is long
+    let mut stack = vec![
+        Step::Split(b, a, right_of(b, a, points)),
+        Step::Emit(b),
+        Step::Split(a, b, right_of(a, b, points)),
+        Step::Emit(a),
+    ];
+    while let Some(step) = stack.pop() {
+        match step {
+            Step::Emit(p) => hull.push(p),
+            Step::Split(a, b, candidates) => {
+                // Of several points equally far out, the one furthest along
+                // a -> b, so the others end up in line with a hull edge
+                let key = |p: &Point<2, N>| {
+                    let along = (b.widen() - a.widen()).dot(&(p.widen() - a.widen()));
+                    (signed_area(&b, &a, p), along)
+                };
+                let Some(&c) = candidates.iter().max_by(|p, q| key(p).partial_cmp(&key(q)).unwrap_or(Ordering::Equal))
+                else {
+                    continue;
+                };
+                stack.push(Step::Split(c, b, right_of(c, b, &candidates)));
+                stack.push(Step::Emit(c));
+                stack.push(Step::Split(a, c, right_of(a, c, &candidates)));
+            }
+        }
+    }
+    hull
+}
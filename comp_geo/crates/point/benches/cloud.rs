@@ -0,0 +1,68 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use point::{Aabb, Point, PointCloud};
+
+const N: usize = 1_000_000;
+
+fn points() -> Vec<Point<2, f64>> {
+    // Deterministic spread without pulling in a random number crate
+    (0..N)
+        .map(|i| {
+            let t = i as f64;
+            [(t * 0.618_033_988).fract() * 1000.0, (t * 0.414_213_562).fract() * 1000.0].into()
+        })
+        .collect()
+}
+
+fn bench_square_distances(c: &mut Criterion) {
+    let aos = points();
+    let soa = PointCloud::from(&aos[..]);
+    let query: Point<2, f64> = [500.0, 500.0].into();
+    let mut group = c.benchmark_group("square_distances");
+    group.bench_function("Vec<Point>", |b| {
+        b.iter(|| aos.iter().map(|p| p.square_distance(black_box(&query))).collect::<Vec<_>>())
+    });
+    group.bench_function("PointCloud", |b| b.iter(|| soa.square_distances(black_box(&query))));
+    group.finish();
+}
+
+fn bench_translate(c: &mut Criterion) {
+    let mut aos = points();
+    let mut soa = PointCloud::from(&aos[..]);
+    let offset: Point<2, f64> = [1.0, -1.0].into();
+    let mut group = c.benchmark_group("translate");
+    group.bench_function("Vec<Point>", |b| {
+        b.iter(|| {
+            for p in aos.iter_mut() {
+                *p = *p + black_box(offset);
+            }
+        })
+    });
+    group.bench_function("PointCloud", |b| b.iter(|| soa.translate(black_box(&offset))));
+    group.finish();
+}
+
+fn bench_bounding_box(c: &mut Criterion) {
+    let aos = points();
+    let soa = PointCloud::from(&aos[..]);
+    let mut group = c.benchmark_group("bounding_box");
+    group.bench_function("Vec<Point>", |b| b.iter(|| Aabb::from_points(black_box(&aos).iter().copied())));
+    group.bench_function("PointCloud", |b| b.iter(|| black_box(&soa).bounding_box()));
+    group.finish();
+}
+
+fn bench_centroid(c: &mut Criterion) {
+    let aos = points();
+    let soa = PointCloud::from(&aos[..]);
+    let mut group = c.benchmark_group("centroid");
+    group.bench_function("Vec<Point>", |b| {
+        b.iter(|| {
+            let sum = black_box(&aos).iter().fold(Point::default(), |acc, p| acc + *p);
+            Point { coords: sum.coords.map(|c: f64| c / N as f64) }
+        })
+    });
+    group.bench_function("PointCloud", |b| b.iter(|| black_box(&soa).centroid()));
+    group.finish();
+}
+
+criterion_group!(benches, bench_square_distances, bench_translate, bench_bounding_box, bench_centroid);
+criterion_main!(benches);
@@ -0,0 +1,174 @@
+use crate::Point;
+use num_traits::Num;
+
+/// Axis-aligned bounding box, closed on all sides.
+/// ```
+/// use point::{Aabb, Point};
+/// let points: Vec<Point<2, i32>> = vec![[1, 5].into(), [-2, 3].into(), [4, 0].into()];
+/// let bb = Aabb::from_points(points).unwrap();
+/// assert_eq!(bb, Aabb { min: [-2, 0].into(), max: [4, 5].into() });
+/// assert!(bb.contains(&[0, 0].into()));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Aabb<const D: usize, N> {
+    pub min: Point<D, N>,
+    pub max: Point<D, N>,
+}
+
+fn min<N: PartialOrd>(a: N, b: N) -> N {
+    if b < a { b } else { a }
+}
+
+fn max<N: PartialOrd>(a: N, b: N) -> N {
+    if b > a { b } else { a }
+}
+
+impl<const D: usize, N: Num + Copy + PartialOrd> Aabb<D, N> {
+    /// The smallest box holding both corners, in any order.
+    pub fn new(a: Point<D, N>, b: Point<D, N>) -> Self {
+        Self {
+            min: Point { coords: std::array::from_fn(|i| min(a.coords[i], b.coords[i])) },
+            max: Point { coords: std::array::from_fn(|i| max(a.coords[i], b.coords[i])) },
+        }
+    }
+
+    /// Returns `None` when `points` is empty.
+    pub fn from_points<I: IntoIterator<Item = Point<D, N>>>(points: I) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        let mut bb = Self { min: first, max: first };
+        for p in points {
+            bb.extend(&p);
+        }
+        Some(bb)
+    }
+
+    /// Grows the box in place so it also holds `p`.
+    pub fn extend(&mut self, p: &Point<D, N>) {
+        for i in 0..D {
+            self.min.coords[i] = min(self.min.coords[i], p.coords[i]);
+            self.max.coords[i] = max(self.max.coords[i], p.coords[i]);
+        }
+    }
+
+    /// The box grown by `margin` on every side.
+    pub fn expand(&self, margin: N) -> Self {
+        Self {
+            min: Point { coords: self.min.coords.map(|c| c - margin) },
+            max: Point { coords: self.max.coords.map(|c| c + margin) },
+        }
+    }
+
+    pub fn contains(&self, p: &Point<D, N>) -> bool {
+        (0..D).all(|i| self.min.coords[i] <= p.coords[i] && p.coords[i] <= self.max.coords[i])
+    }
+
+    pub fn contains_box(&self, other: &Self) -> bool {
+        self.contains(&other.min) && self.contains(&other.max)
+    }
+
+    /// True when the boxes share at least one point, including touching faces.
+    pub fn intersects(&self, other: &Self) -> bool {
+        (0..D).all(|i| self.min.coords[i] <= other.max.coords[i] && other.min.coords[i] <= self.max.coords[i])
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        Self {
+            min: Point { coords: std::array::from_fn(|i| min(self.min.coords[i], other.min.coords[i])) },
+            max: Point { coords: std::array::from_fn(|i| max(self.max.coords[i], other.max.coords[i])) },
+        }
+    }
+
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        self.intersects(other).then(|| Self {
+            min: Point { coords: std::array::from_fn(|i| max(self.min.coords[i], other.min.coords[i])) },
+            max: Point { coords: std::array::from_fn(|i| min(self.max.coords[i], other.max.coords[i])) },
+        })
+    }
+
+    /// Midpoint of the box. Truncates for integer coordinates.
+    pub fn center(&self) -> Point<D, N> {
+        let two = N::one() + N::one();
+        Point { coords: std::array::from_fn(|i| (self.min.coords[i] + self.max.coords[i]) / two) }
+    }
+
+    /// Side lengths along each axis.
+    pub fn extent(&self) -> Point<D, N> {
+        self.max - self.min
+    }
+
+    /// Square distance from `p` to the nearest point of the box, zero if inside.
+    /// ```
+    /// use point::Aabb;
+    /// let bb = Aabb::new([0, 0].into(), [2, 2].into());
+    /// assert_eq!(bb.square_distance_to_point(&[5, 6].into()), 25);
+    /// assert_eq!(bb.square_distance_to_point(&[1, 6].into()), 16);
+    /// ```
+    pub fn square_distance_to_point(&self, p: &Point<D, N>) -> N {
+        (0..D).fold(N::zero(), |acc, i| {
+            let c = p.coords[i];
+            let d = if c < self.min.coords[i] {
+                self.min.coords[i] - c
+            } else if c > self.max.coords[i] {
+                c - self.max.coords[i]
+            } else {
+                N::zero()
+            };
+            acc + d * d
+        })
+    }
+
+    /// Square distance between the closest points of two boxes, zero if they intersect.
+    pub fn square_distance(&self, other: &Self) -> N {
+        (0..D).fold(N::zero(), |acc, i| {
+            let d = if other.max.coords[i] < self.min.coords[i] {
+                self.min.coords[i] - other.max.coords[i]
+            } else if other.min.coords[i] > self.max.coords[i] {
+                other.min.coords[i] - self.max.coords[i]
+            } else {
+                N::zero()
+            };
+            acc + d * d
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_points() {
+        let empty: Vec<Point<3, f64>> = vec![];
+        assert_eq!(Aabb::from_points(empty), None);
+        let bb = Aabb::from_points(vec![Point { coords: [1.0, 2.0, 3.0] }, Point { coords: [-1.0, 5.0, 0.0] }]).unwrap();
+        assert_eq!(bb.min, [-1.0, 2.0, 0.0].into());
+        assert_eq!(bb.max, [1.0, 5.0, 3.0].into());
+        assert_eq!(bb.center(), [0.0, 3.5, 1.5].into());
+        assert_eq!(bb.extent(), [2.0, 3.0, 3.0].into());
+    }
+
+    #[test]
+    fn intersection_and_union() {
+        let a = Aabb::new([0, 0].into(), [4, 4].into());
+        let b = Aabb::new([6, 3].into(), [2, 8].into());
+        let c = Aabb::new([5, 5].into(), [6, 6].into());
+        assert!(a.intersects(&b));
+        assert_eq!(a.intersection(&b), Some(Aabb::new([2, 3].into(), [4, 4].into())));
+        assert_eq!(a.union(&b), Aabb::new([0, 0].into(), [6, 8].into()));
+        assert!(!a.intersects(&c));
+        assert_eq!(a.intersection(&c), None);
+        assert_eq!(a.square_distance(&c), 2);
+        assert!(a.expand(1).intersects(&c));
+        assert!(a.union(&c).contains_box(&c));
+    }
+
+    #[test]
+    fn touching() {
+        let a = Aabb::new([0, 0].into(), [1, 1].into());
+        let b = Aabb::new([1, 1].into(), [2, 2].into());
+        assert_eq!(a.intersection(&b), Some(Aabb::new([1, 1].into(), [1, 1].into())));
+        assert_eq!(a.square_distance(&b), 0);
+        assert!(a.contains(&[1, 0].into()));
+    }
+}
@@ -0,0 +1,151 @@
+use crate::Point;
+use num_traits::Float;
+
+/// How close two floats must be to count as equal.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Tolerance<F> {
+    /// `|a - b| <= eps`
+    Absolute(F),
+    /// `|a - b| <= eps * max(|a|, |b|)`
+    Relative(F),
+    /// At most this many representable floats apart.
+    Ulps(u32),
+}
+
+/// Float types that support tolerance based comparison.
+pub trait ApproxEq: Float {
+    /// Number of representable values between `self` and `other`, or `None` if either is NaN.
+    fn ulps_between(self, other: Self) -> Option<u64>;
+
+    fn approx_eq(self, other: Self, tolerance: Tolerance<Self>) -> bool {
+        match tolerance {
+            Tolerance::Absolute(eps) => (self - other).abs() <= eps,
+            Tolerance::Relative(eps) => (self - other).abs() <= eps * self.abs().max(other.abs()),
+            Tolerance::Ulps(n) => self.ulps_between(other).is_some_and(|d| d <= n as u64),
+        }
+    }
+}
+
+impl ApproxEq for f64 {
+    fn ulps_between(self, other: Self) -> Option<u64> {
+        if self.is_nan() || other.is_nan() {
+            return None;
+        }
+        // Remap the sign-magnitude bits onto a monotonic integer line
+        let key = |f: f64| {
+            let bits = f.to_bits() as i64;
+            if bits < 0 { i64::MIN as i128 - bits as i128 } else { bits as i128 }
+        };
+        Some((key(self) - key(other)).unsigned_abs() as u64)
+    }
+}
+
+impl ApproxEq for f32 {
+    fn ulps_between(self, other: Self) -> Option<u64> {
+        if self.is_nan() || other.is_nan() {
+            return None;
+        }
+        let key = |f: f32| {
+            let bits = f.to_bits() as i32;
+            if bits < 0 { i32::MIN as i64 - bits as i64 } else { bits as i64 }
+        };
+        Some((key(self) - key(other)).unsigned_abs())
+    }
+}
+
+impl<const D: usize, N: ApproxEq> Point<D, N> {
+    /// True when every coordinate is within `tolerance`.
+    /// ```
+    /// use point::{Point, Tolerance};
+    /// let a = Point { coords: [0.1 + 0.2, 1.0] };
+    /// let b = Point { coords: [0.3, 1.0] };
+    /// assert_ne!(a, b);
+    /// assert!(a.approx_eq(&b, Tolerance::Ulps(1)));
+    /// assert!(a.approx_eq(&b, Tolerance::Absolute(1e-12)));
+    /// ```
+    pub fn approx_eq(&self, other: &Self, tolerance: Tolerance<N>) -> bool {
+        self.coords
+            .iter()
+            .zip(other.coords.iter())
+            .all(|(&a, &b)| a.approx_eq(b, tolerance))
+    }
+}
+
+impl<const D: usize, N: Float> Point<D, N> {
+    /// Integer grid cell of size `cell` that this point rounds to.
+    /// The result is `Eq + Hash`, so it can key a `HashMap` for
+    /// deduplicating or welding nearby vertices. Points closer than
+    /// `cell` can still land in neighbouring cells when they straddle
+    /// a cell boundary. Cells beyond the range of `i64` saturate to
+    /// `i64::MIN` or `i64::MAX` by sign, and NaN goes to `i64::MAX`.
+    /// ```
+    /// use point::Point;
+    /// use std::collections::HashMap;
+    /// let points: Vec<Point<2, f64>> = vec![[0.0, 1.0].into(), [1e-9, 1.0].into(), [2.0, 2.0].into()];
+    /// let mut welded = HashMap::new();
+    /// for p in &points {
+    ///     welded.entry(p.quantize(1e-6)).or_insert(*p);
+    /// }
+    /// assert_eq!(welded.len(), 2);
+    /// ```
+    pub fn quantize(&self, cell: N) -> Point<D, i64> {
+        Point {
+            coords: self.coords.map(|c| {
+                let q = (c / cell).round();
+                q.to_i64().unwrap_or(if q < N::zero() { i64::MIN } else { i64::MAX })
+            }),
+        }
+    }
+
+    /// The point moved to the nearest multiple of `cell` on each axis.
+    pub fn snap(&self, cell: N) -> Self {
+        Point {
+            coords: self.coords.map(|c| (c / cell).round() * cell),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn tolerances() {
+        assert!(1.0f64.approx_eq(1.0 + 1e-10, Tolerance::Absolute(1e-9)));
+        assert!(!1.0f64.approx_eq(1.1, Tolerance::Absolute(1e-9)));
+        assert!(1e10f64.approx_eq(1e10 + 1.0, Tolerance::Relative(1e-9)));
+        assert!(!1e-10f64.approx_eq(2e-10, Tolerance::Relative(1e-9)));
+        assert!(!f64::NAN.approx_eq(f64::NAN, Tolerance::Ulps(u32::MAX)));
+    }
+
+    #[test]
+    fn ulps() {
+        assert_eq!(1.0f64.ulps_between(1.0), Some(0));
+        assert_eq!(0.0f64.ulps_between(-0.0), Some(0));
+        assert_eq!(1.0f64.ulps_between(f64::from_bits(1.0f64.to_bits() + 3)), Some(3));
+        assert_eq!(f64::MIN_POSITIVE.ulps_between(-f64::MIN_POSITIVE), Some(2 * f64::MIN_POSITIVE.to_bits()));
+        assert_eq!(1.0f32.ulps_between(f32::from_bits(1.0f32.to_bits() - 2)), Some(2));
+    }
+
+    #[test]
+    fn hash_integer_points() {
+        let points: Vec<Point<2, i32>> = vec![[1, 2].into(), [3, 4].into(), [1, 2].into()];
+        let unique: HashSet<_> = points.into_iter().collect();
+        assert_eq!(unique.len(), 2);
+        let mut sorted: Vec<Point<2, i32>> = vec![[3, 4].into(), [1, 5].into(), [1, 2].into()];
+        sorted.sort();
+        assert_eq!(sorted, vec![[1, 2].into(), [1, 5].into(), [3, 4].into()]);
+    }
+
+    #[test]
+    fn snapping() {
+        let p: Point<2, f64> = [0.26, -0.74].into();
+        assert_eq!(p.quantize(0.5), [1, -1].into());
+        assert_eq!(p.snap(0.5), [0.5, -0.5].into());
+        assert_eq!(Point { coords: [f64::NAN] }.quantize(1.0), Point { coords: [i64::MAX] });
+        let far: Point<2, f64> = [-1e300, 1e300].into();
+        assert_eq!(far.quantize(1.0), [i64::MIN, i64::MAX].into());
+        assert_eq!(Point { coords: [f64::NEG_INFINITY] }.quantize(1.0), Point { coords: [i64::MIN] });
+    }
+}
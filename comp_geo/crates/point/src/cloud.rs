@@ -0,0 +1,207 @@
+use crate::{Aabb, Point};
+use num_traits::{Num, NumCast};
+
+/// Structure-of-arrays point storage: one contiguous `Vec` per axis.
+///
+/// Bulk operations walk each axis as a flat slice so the compiler can
+/// vectorize them. See `benches/cloud.rs` for a comparison against
+/// the same operations on a `Vec<Point<D, N>>`.
+/// ```
+/// use point::{Point, PointCloud};
+/// let mut cloud: PointCloud<2, f64> = vec![[0.0, 0.0].into(), [2.0, 4.0].into()].into_iter().collect();
+/// cloud.translate(&[1.0, 1.0].into());
+/// assert_eq!(cloud.centroid(), Some([2.0, 3.0].into()));
+/// assert_eq!(cloud.square_distances(&[1.0, 1.0].into()), vec![0.0, 20.0]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct PointCloud<const D: usize, N> {
+    coords: [Vec<N>; D],
+}
+
+impl<const D: usize, N> Default for PointCloud<D, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const D: usize, N> PointCloud<D, N> {
+    pub fn new() -> Self {
+        Self { coords: std::array::from_fn(|_| Vec::new()) }
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self { coords: std::array::from_fn(|_| Vec::with_capacity(capacity)) }
+    }
+
+    pub fn len(&self) -> usize {
+        self.coords.first().map_or(0, Vec::len)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// All coordinates along `axis`, one per point.
+    pub fn axis(&self, axis: usize) -> &[N] {
+        &self.coords[axis]
+    }
+
+    pub fn push(&mut self, p: Point<D, N>) {
+        for (axis, c) in self.coords.iter_mut().zip(p.coords) {
+            axis.push(c);
+        }
+    }
+}
+
+impl<const D: usize, N: Copy> PointCloud<D, N> {
+    pub fn get(&self, i: usize) -> Option<Point<D, N>> {
+        (i < self.len()).then(|| Point { coords: std::array::from_fn(|axis| self.coords[axis][i]) })
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Point<D, N>> + '_ {
+        (0..self.len()).map(|i| Point { coords: std::array::from_fn(|axis| self.coords[axis][i]) })
+    }
+}
+
+impl<const D: usize, N: Num + Copy> PointCloud<D, N> {
+    pub fn translate(&mut self, offset: &Point<D, N>) {
+        for (axis, &d) in self.coords.iter_mut().zip(offset.coords.iter()) {
+            for c in axis.iter_mut() {
+                *c = *c + d;
+            }
+        }
+    }
+
+    /// Scales about the origin.
+    pub fn scale(&mut self, factor: N) {
+        for axis in self.coords.iter_mut() {
+            for c in axis.iter_mut() {
+                *c = *c * factor;
+            }
+        }
+    }
+
+    /// Square distance from `query` to every point, in order.
+    pub fn square_distances(&self, query: &Point<D, N>) -> Vec<N> {
+        let mut out = vec![N::zero(); self.len()];
+        for (axis, &q) in self.coords.iter().zip(query.coords.iter()) {
+            for (o, &c) in out.iter_mut().zip(axis.iter()) {
+                let d = c - q;
+                *o = *o + d * d;
+            }
+        }
+        out
+    }
+}
+
+impl<const D: usize, N: Num + Copy + PartialOrd> PointCloud<D, N> {
+    pub fn bounding_box(&self) -> Option<Aabb<D, N>> {
+        if self.is_empty() {
+            return None;
+        }
+        let mut min = [N::zero(); D];
+        let mut max = [N::zero(); D];
+        for (i, axis) in self.coords.iter().enumerate() {
+            (min[i], max[i]) = min_max(axis);
+        }
+        Some(Aabb { min: Point { coords: min }, max: Point { coords: max } })
+    }
+}
+
+impl<const D: usize, N: Num + Copy + NumCast> PointCloud<D, N> {
+    /// Mean of all points, summed and divided in `N`, so it truncates for
+    /// integer coordinates. `None` for an empty cloud, and also when the
+    /// number of points does not fit in `N`, as with 200 points of `i8`.
+    pub fn centroid(&self) -> Option<Point<D, N>> {
+        let n = N::from(self.len()).filter(|_| !self.is_empty())?;
+        Some(Point {
+            coords: std::array::from_fn(|axis| sum(&self.coords[axis]) / n),
+        })
+    }
+}
+
+// Reductions keep LANES independent accumulators so that the loop
+// bodies have no cross-iteration dependency and can be vectorized.
+const LANES: usize = 8;
+
+fn sum<N: Num + Copy>(xs: &[N]) -> N {
+    let mut acc = [N::zero(); LANES];
+    let chunks = xs.chunks_exact(LANES);
+    let rest = chunks.remainder();
+    for chunk in chunks {
+        for i in 0..LANES {
+            acc[i] = acc[i] + chunk[i];
+        }
+    }
+    acc.iter().chain(rest).fold(N::zero(), |a, &b| a + b)
+}
+
+// `xs` must not be empty
+fn min_max<N: Copy + PartialOrd>(xs: &[N]) -> (N, N) {
+    let mut lo = [xs[0]; LANES];
+    let mut hi = [xs[0]; LANES];
+    let chunks = xs.chunks_exact(LANES);
+    let rest = chunks.remainder();
+    for chunk in chunks {
+        for i in 0..LANES {
+            lo[i] = if chunk[i] < lo[i] { chunk[i] } else { lo[i] };
+            hi[i] = if chunk[i] > hi[i] { chunk[i] } else { hi[i] };
+        }
+    }
+    let lo = lo.iter().chain(rest).fold(xs[0], |a, &b| if b < a { b } else { a });
+    let hi = hi.iter().chain(rest).fold(xs[0], |a, &b| if b > a { b } else { a });
+    (lo, hi)
+}
+
+impl<const D: usize, N> FromIterator<Point<D, N>> for PointCloud<D, N> {
+    fn from_iter<I: IntoIterator<Item = Point<D, N>>>(iter: I) -> Self {
+        let mut cloud = Self::new();
+        for p in iter {
+            cloud.push(p);
+        }
+        cloud
+    }
+}
+
+impl<const D: usize, N: Copy> From<&[Point<D, N>]> for PointCloud<D, N> {
+    fn from(points: &[Point<D, N>]) -> Self {
+        Self { coords: std::array::from_fn(|axis| points.iter().map(|p| p.coords[axis]).collect()) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn layout() {
+        let points: Vec<Point<3, i32>> = vec![[1, 2, 3].into(), [4, 5, 6].into()];
+        let cloud = PointCloud::from(&points[..]);
+        assert_eq!(cloud.len(), 2);
+        assert_eq!(cloud.axis(1), &[2, 5]);
+        assert_eq!(cloud.get(1), Some([4, 5, 6].into()));
+        assert_eq!(cloud.get(2), None);
+        assert_eq!(cloud.iter().collect::<Vec<_>>(), points);
+    }
+
+    #[test]
+    fn bulk_ops() {
+        let mut cloud: PointCloud<2, i64> = [[1, -1], [3, 5], [-2, 2]].into_iter().map(Point::from).collect();
+        assert_eq!(cloud.bounding_box(), Some(Aabb { min: [-2, -1].into(), max: [3, 5].into() }));
+        cloud.scale(2);
+        cloud.translate(&[1, 0].into());
+        assert_eq!(cloud.iter().collect::<Vec<_>>(), vec![[3, -2].into(), [7, 10].into(), [-3, 4].into()]);
+        assert_eq!(cloud.centroid(), Some([2, 4].into()));
+        let crowd: PointCloud<1, i8> = (0..200).map(|_| Point::from([0])).collect();
+        assert_eq!(crowd.centroid(), None);
+        assert_eq!(cloud.square_distances(&[0, 0].into()), vec![13, 149, 25]);
+    }
+
+    #[test]
+    fn empty() {
+        let cloud = PointCloud::<2, f64>::new();
+        assert!(cloud.is_empty());
+        assert_eq!(cloud.bounding_box(), None);
+        assert_eq!(cloud.centroid(), None);
+    }
+}
@@ -0,0 +1,161 @@
+use crate::{Point, Rational, Rational128, Rational64};
+use num_traits::{Num, PrimInt, Signed, Zero};
+use std::cmp::Ordering;
+use std::fmt::Debug;
+
+/// A signed numeric coordinate type.
+///
+/// `Field` is the type that quotients of coordinates are computed in,
+/// e.g. the parameter of an intersection along a segment: rationals for
+/// integers, so such results are exact, and the type itself for floats
+/// and rationals. `Wide` holds products of coordinate differences, such
+/// as cross products, without overflowing.
+/// ```
+/// use point::{Coordinate, Rational128};
+/// assert_eq!(3i32.to_field() / 4i32.to_field(), Rational128::new(3, 4));
+/// assert_eq!(3.0f64.to_field() / 4.0, 0.75);
+/// assert_eq!(i32::MAX.widen() * 2, 4294967294i64);
+/// ```
+pub trait Coordinate: Num + Copy + PartialOrd + Debug {
+    type Field: Coordinate<Field = Self::Field>;
+    type Wide: Coordinate;
+
+    fn to_field(self) -> Self::Field;
+
+    fn widen(self) -> Self::Wide;
+
+    fn to_f64(self) -> f64;
+}
+
+/// Which side of the directed line through `a` and `b` the point `c` is
+/// on: `Greater` for left (counter-clockwise), `Less` for right and
+/// `Equal` when the three are collinear. Exact for integer coordinates.
+/// ```
+/// use point::orientation;
+/// use std::cmp::Ordering;
+/// assert_eq!(orientation(&[0, 0].into(), &[2, 0].into(), &[1, 1].into()), Ordering::Greater);
+/// assert_eq!(orientation(&[0, 0].into(), &[2, 2].into(), &[5, 5].into()), Ordering::Equal);
+/// ```
+pub fn orientation<N: Coordinate>(a: &Point<2, N>, b: &Point<2, N>, c: &Point<2, N>) -> Ordering {
+    let (a, b, c) = (a.widen(), b.widen(), c.widen());
+    (b - a)
+        .cross(&(c - a))
+        .partial_cmp(&N::Wide::zero())
+        .unwrap_or(Ordering::Equal)
+}
+
+// Orientation tests take products of two coordinate differences in the
+// wide type, so they are exact for `i32` coordinates of magnitude below
+// 2^30 and `i64` below 2^62. Intersection points have numerators of degree
+// three, so `i32` works in `Rational128`, which holds them for every
+// `i32` up to 2^30; `i64` intersections only fit below about 2^40 and
+// panic with "Rational overflow" past that.
+macro_rules! integer_coordinate {
+    ($($int:ty => $field:ty, $wide:ty;)*) => {$(
+        impl Coordinate for $int {
+            type Field = $field;
+            type Wide = $wide;
+
+            fn to_field(self) -> $field {
+                <$field>::from_integer(self.into())
+            }
+
+            fn widen(self) -> $wide {
+                self as $wide
+            }
+
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+        }
+    )*};
+}
+
+integer_coordinate! {
+    i8 => Rational64, i64;
+    i16 => Rational64, i64;
+    i32 => Rational128, i64;
+    i64 => Rational128, i128;
+}
+
+impl Coordinate for i128 {
+    type Field = Rational128;
+    type Wide = i128;
+
+    fn to_field(self) -> Rational128 {
+        Rational128::from_integer(self)
+    }
+
+    fn widen(self) -> i128 {
+        self
+    }
+
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+}
+
+impl Coordinate for f32 {
+    type Field = f32;
+    type Wide = f32;
+
+    fn to_field(self) -> f32 {
+        self
+    }
+
+    fn widen(self) -> f32 {
+        self
+    }
+
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+}
+
+impl Coordinate for f64 {
+    type Field = f64;
+    type Wide = f64;
+
+    fn to_field(self) -> f64 {
+        self
+    }
+
+    fn widen(self) -> f64 {
+        self
+    }
+
+    fn to_f64(self) -> f64 {
+        self
+    }
+}
+
+impl<T: PrimInt + Signed + Debug> Coordinate for Rational<T> {
+    type Field = Self;
+    type Wide = Self;
+
+    fn to_field(self) -> Self {
+        self
+    }
+
+    fn widen(self) -> Self {
+        self
+    }
+
+    fn to_f64(self) -> f64 {
+        Rational::to_f64(&self)
+    }
+}
+
+impl<const D: usize, N: Coordinate> Point<D, N> {
+    pub fn to_field(&self) -> Point<D, N::Field> {
+        Point { coords: self.coords.map(N::to_field) }
+    }
+
+    pub fn widen(&self) -> Point<D, N::Wide> {
+        Point { coords: self.coords.map(N::widen) }
+    }
+
+    pub fn to_f64(&self) -> Point<D, f64> {
+        Point { coords: self.coords.map(N::to_f64) }
+    }
+}
@@ -0,0 +1,216 @@
+// Conversions to and from other geometry crates, each behind a feature
+// named after the crate.
+
+#[cfg(feature = "nalgebra")]
+mod nalgebra_impl {
+    use crate::Point;
+    use nalgebra::{SVector, Scalar};
+
+    impl<const D: usize, N: Scalar> From<nalgebra::Point<N, D>> for Point<D, N> {
+        fn from(p: nalgebra::Point<N, D>) -> Self {
+            Point { coords: p.into() }
+        }
+    }
+
+    impl<const D: usize, N: Scalar> From<Point<D, N>> for nalgebra::Point<N, D> {
+        fn from(p: Point<D, N>) -> Self {
+            p.coords.into()
+        }
+    }
+
+    impl<const D: usize, N: Scalar> From<SVector<N, D>> for Point<D, N> {
+        fn from(v: SVector<N, D>) -> Self {
+            Point { coords: v.into() }
+        }
+    }
+
+    impl<const D: usize, N: Scalar> From<Point<D, N>> for SVector<N, D> {
+        fn from(p: Point<D, N>) -> Self {
+            p.coords.into()
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn round_trip() {
+            let p: Point<3, f64> = [1.0, 2.0, 3.0].into();
+            let n: nalgebra::Point3<f64> = p.into();
+            assert_eq!(n, nalgebra::Point3::new(1.0, 2.0, 3.0));
+            assert_eq!(Point::from(n), p);
+
+            let v: nalgebra::Vector2<i32> = Point { coords: [4, -5] }.into();
+            assert_eq!(v, nalgebra::Vector2::new(4, -5));
+            assert_eq!(Point::from(v), Point { coords: [4, -5] });
+        }
+    }
+}
+
+#[cfg(feature = "glam")]
+mod glam_impl {
+    use crate::Point;
+
+    macro_rules! glam_conversions {
+        ($($glam:ty => $d:literal, $n:ty;)*) => {$(
+            impl From<$glam> for Point<$d, $n> {
+                fn from(v: $glam) -> Self {
+                    Point { coords: v.to_array() }
+                }
+            }
+
+            impl From<Point<$d, $n>> for $glam {
+                fn from(p: Point<$d, $n>) -> Self {
+                    <$glam>::from_array(p.coords)
+                }
+            }
+        )*};
+    }
+
+    glam_conversions! {
+        glam::Vec2 => 2, f32;
+        glam::DVec2 => 2, f64;
+        glam::IVec2 => 2, i32;
+        glam::I64Vec2 => 2, i64;
+        glam::Vec3 => 3, f32;
+        glam::DVec3 => 3, f64;
+        glam::IVec3 => 3, i32;
+        glam::I64Vec3 => 3, i64;
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn round_trip() {
+            let p: Point<2, f32> = [1.5, -2.0].into();
+            let v = glam::Vec2::from(p);
+            assert_eq!(v, glam::Vec2::new(1.5, -2.0));
+            assert_eq!(Point::from(v), p);
+
+            let p: Point<3, i32> = [1, 2, 3].into();
+            assert_eq!(Point::from(glam::IVec3::from(p)), p);
+        }
+    }
+}
+
+#[cfg(feature = "mint")]
+mod mint_impl {
+    use crate::Point;
+
+    impl<N> From<mint::Point2<N>> for Point<2, N> {
+        fn from(p: mint::Point2<N>) -> Self {
+            Point { coords: [p.x, p.y] }
+        }
+    }
+
+    impl<N> From<Point<2, N>> for mint::Point2<N> {
+        fn from(p: Point<2, N>) -> Self {
+            let [x, y] = p.coords;
+            mint::Point2 { x, y }
+        }
+    }
+
+    impl<N> From<mint::Vector2<N>> for Point<2, N> {
+        fn from(v: mint::Vector2<N>) -> Self {
+            Point { coords: [v.x, v.y] }
+        }
+    }
+
+    impl<N> From<Point<2, N>> for mint::Vector2<N> {
+        fn from(p: Point<2, N>) -> Self {
+            let [x, y] = p.coords;
+            mint::Vector2 { x, y }
+        }
+    }
+
+    impl<N> From<mint::Point3<N>> for Point<3, N> {
+        fn from(p: mint::Point3<N>) -> Self {
+            Point { coords: [p.x, p.y, p.z] }
+        }
+    }
+
+    impl<N> From<Point<3, N>> for mint::Point3<N> {
+        fn from(p: Point<3, N>) -> Self {
+            let [x, y, z] = p.coords;
+            mint::Point3 { x, y, z }
+        }
+    }
+
+    impl<N> From<mint::Vector3<N>> for Point<3, N> {
+        fn from(v: mint::Vector3<N>) -> Self {
+            Point { coords: [v.x, v.y, v.z] }
+        }
+    }
+
+    impl<N> From<Point<3, N>> for mint::Vector3<N> {
+        fn from(p: Point<3, N>) -> Self {
+            let [x, y, z] = p.coords;
+            mint::Vector3 { x, y, z }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn round_trip() {
+            let p: Point<2, f64> = [1.0, 2.0].into();
+            let m: mint::Point2<f64> = p.into();
+            assert_eq!((m.x, m.y), (1.0, 2.0));
+            assert_eq!(Point::from(m), p);
+
+            let p: Point<3, i64> = [1, 2, 3].into();
+            let v: mint::Vector3<i64> = p.into();
+            assert_eq!(Point::from(v), p);
+        }
+    }
+}
+
+#[cfg(feature = "geo-types")]
+mod geo_types_impl {
+    use crate::Point;
+    use geo_types::{Coord, CoordNum};
+
+    impl<N: CoordNum> From<Coord<N>> for Point<2, N> {
+        fn from(c: Coord<N>) -> Self {
+            Point { coords: [c.x, c.y] }
+        }
+    }
+
+    impl<N: CoordNum> From<Point<2, N>> for Coord<N> {
+        fn from(p: Point<2, N>) -> Self {
+            Coord { x: p.x(), y: p.y() }
+        }
+    }
+
+    impl<N: CoordNum> From<geo_types::Point<N>> for Point<2, N> {
+        fn from(p: geo_types::Point<N>) -> Self {
+            p.0.into()
+        }
+    }
+
+    impl<N: CoordNum> From<Point<2, N>> for geo_types::Point<N> {
+        fn from(p: Point<2, N>) -> Self {
+            geo_types::Point(p.into())
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn round_trip() {
+            let p: Point<2, f64> = [1.0, 2.0].into();
+            let g: geo_types::Point<f64> = p.into();
+            assert_eq!(g, geo_types::Point::new(1.0, 2.0));
+            assert_eq!(Point::from(g), p);
+            let c: Coord<i32> = Point { coords: [3, 4] }.into();
+            assert_eq!(Point::from(c), Point { coords: [3, 4] });
+        }
+    }
+}
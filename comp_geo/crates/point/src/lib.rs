@@ -2,7 +2,41 @@ use num_traits::Num;
 use std::fmt::Debug;
 use std::ops::{Add, Sub};
 
-#[derive(Debug, Copy, Clone, PartialEq)]
+mod aabb;
+pub use aabb::Aabb;
+
+mod approx;
+pub use approx::{ApproxEq, Tolerance};
+
+mod cloud;
+pub use cloud::PointCloud;
+
+mod coordinate;
+pub use coordinate::{orientation, Coordinate};
+
+mod interop;
+
+mod metric;
+pub use metric::{Chebyshev, Euclidean, Manhattan, Metric, Minkowski, SquaredEuclidean};
+
+mod order;
+pub use order::{direction_cmp, polar_cmp, FloatLexicographic};
+
+mod plane;
+pub use plane::Plane;
+
+mod rational;
+pub use rational::{ParseRationalError, Rational, Rational128, Rational64};
+
+#[cfg(feature = "serde")]
+mod serde_impl;
+
+mod transform;
+pub use transform::{Homogeneous, Transform2, Transform3};
+
+/// Point or vector in `D` dimensions. `Eq`, `Hash` and the lexicographic
+/// `Ord` are available whenever the coordinate type has them, e.g. integers.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct Point<const D: usize, N> {
     pub coords: [N; D],
 }
@@ -49,6 +83,35 @@ impl<N: Copy> Point<3, N> {
     pub fn z(&self) -> N {
         self.coords[2]
     }
+
+    /// Vector perpendicular to both, following the right-hand rule.
+    /// ```
+    /// use point::Point;
+    /// let x: Point<3, i32> = [1, 0, 0].into();
+    /// let y: Point<3, i32> = [0, 1, 0].into();
+    /// assert_eq!(x.cross(&y), [0, 0, 1].into());
+    /// ```
+    pub fn cross(&self, other: &Self) -> Self
+    where
+        N: Num
+    {
+        Point {
+            coords: [
+                self.y() * other.z() - self.z() * other.y(),
+                self.z() * other.x() - self.x() * other.z(),
+                self.x() * other.y() - self.y() * other.x(),
+            ],
+        }
+    }
+
+    /// `self · (b × c)`, the signed volume of the parallelepiped spanned
+    /// by the three vectors. Positive when they form a right-handed system.
+    pub fn triple_product(&self, b: &Self, c: &Self) -> N
+    where
+        N: Num
+    {
+        self.dot(&b.cross(c))
+    }
 }
 
 /// Square Distance between two points
@@ -69,6 +132,13 @@ impl<const D: usize, N: Num + Copy> Point<D, N> {
             .map(|(&a, &b)| (a - b) * (a - b))
             .sum()
     }
+
+    pub fn dot(&self, other: &Self) -> N {
+        self.coords
+            .iter()
+            .zip(other.coords.iter())
+            .fold(N::zero(), |acc, (&a, &b)| acc + a * b)
+    }
 }
 /// Addition betweein points
 /// ```
@@ -95,7 +165,7 @@ impl<const D: usize, N: Num + Copy> Add for Point<D, N> {
 /// let p2 = Point {coords: [1, -1]};
 /// assert_eq!(&p1 + &p2, Point {coords: [4, 3]})
 /// ```
-impl<'a, 'b, const D: usize, N: Num + Copy> Add<&'b Point<D, N>> for &'a Point<D, N> {
+impl<'b, const D: usize, N: Num + Copy> Add<&'b Point<D, N>> for &Point<D, N> {
     type Output = Point<D, N>;
 
     fn add(self, rhs: &'b Point<D, N>) -> Self::Output {
@@ -116,7 +186,7 @@ impl<const D: usize, N: Num + Copy> Sub for Point<D, N> {
     }
 }
 
-impl<'a, 'b, const D: usize, N: Num + Copy> Sub<&'b Point<D, N>> for &'a Point<D, N> {
+impl<'b, const D: usize, N: Num + Copy> Sub<&'b Point<D, N>> for &Point<D, N> {
     type Output = Point<D, N>;
 
     fn sub(self, rhs: &'b Point<D, N>) -> Self::Output {
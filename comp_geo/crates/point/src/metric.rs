@@ -0,0 +1,184 @@
+use crate::Point;
+use num_traits::{Float, Num};
+use std::iter::Sum;
+
+/// A distance function between points, for code such as spatial indexes
+/// and clustering that should work with more than one metric.
+///
+/// `axis_distance` is the distance between two points that differ only
+/// along a single axis. For every metric here it is a lower bound on
+/// `distance` for any pair whose coordinates on that axis are `a` and
+/// `b`, which is what a kd-tree needs to prune a subtree.
+/// ```
+/// use point::{Chebyshev, Manhattan, Metric, Point};
+/// let p: Point<2, i32> = [1, 1].into();
+/// let q: Point<2, i32> = [4, -3].into();
+/// assert_eq!(Manhattan.distance(&p, &q), 7);
+/// assert_eq!(Chebyshev.distance(&p, &q), 4);
+/// ```
+pub trait Metric<N> {
+    type Output: PartialOrd + Copy;
+
+    fn distance<const D: usize>(&self, a: &Point<D, N>, b: &Point<D, N>) -> Self::Output;
+
+    fn axis_distance(&self, a: N, b: N) -> Self::Output;
+}
+
+/// The sum of squared coordinate differences, which orders points the same
+/// way `Euclidean` does. It is computed in `N` itself, so integer results
+/// are exact only while they fit: for `i32` that stops at differences
+/// around 46 000. Differences are taken larger minus smaller, so unsigned
+/// coordinates work too.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct SquaredEuclidean;
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct Euclidean;
+
+/// Sum of absolute coordinate differences, also called taxicab or L1.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct Manhattan;
+
+/// Largest absolute coordinate difference, also called L∞.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct Chebyshev;
+
+/// The Lp distance. `p` should be at least 1, below that the triangle
+/// inequality does not hold. An infinite `p` gives the Chebyshev distance.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Minkowski<F> {
+    pub p: F,
+}
+
+fn abs_diff<N: Num + PartialOrd>(a: N, b: N) -> N {
+    if a > b { a - b } else { b - a }
+}
+
+impl<N: Num + Copy + PartialOrd> Metric<N> for SquaredEuclidean {
+    type Output = N;
+
+    fn distance<const D: usize>(&self, a: &Point<D, N>, b: &Point<D, N>) -> N {
+        a.coords
+            .iter()
+            .zip(b.coords.iter())
+            .fold(N::zero(), |acc, (&x, &y)| acc + self.axis_distance(x, y))
+    }
+
+    fn axis_distance(&self, a: N, b: N) -> N {
+        let d = abs_diff(a, b);
+        d * d
+    }
+}
+
+impl<N: Float + Sum> Metric<N> for Euclidean {
+    type Output = N;
+
+    fn distance<const D: usize>(&self, a: &Point<D, N>, b: &Point<D, N>) -> N {
+        a.square_distance(b).sqrt()
+    }
+
+    fn axis_distance(&self, a: N, b: N) -> N {
+        (a - b).abs()
+    }
+}
+
+impl<N: Num + Copy + PartialOrd> Metric<N> for Manhattan {
+    type Output = N;
+
+    fn distance<const D: usize>(&self, a: &Point<D, N>, b: &Point<D, N>) -> N {
+        a.coords
+            .iter()
+            .zip(b.coords.iter())
+            .fold(N::zero(), |acc, (&x, &y)| acc + abs_diff(x, y))
+    }
+
+    fn axis_distance(&self, a: N, b: N) -> N {
+        abs_diff(a, b)
+    }
+}
+
+impl<N: Num + Copy + PartialOrd> Metric<N> for Chebyshev {
+    type Output = N;
+
+    fn distance<const D: usize>(&self, a: &Point<D, N>, b: &Point<D, N>) -> N {
+        a.coords.iter().zip(b.coords.iter()).fold(N::zero(), |acc, (&x, &y)| {
+            let d = abs_diff(x, y);
+            if d > acc { d } else { acc }
+        })
+    }
+
+    fn axis_distance(&self, a: N, b: N) -> N {
+        abs_diff(a, b)
+    }
+}
+
+impl<N: Float> Metric<N> for Minkowski<N> {
+    type Output = N;
+
+    fn distance<const D: usize>(&self, a: &Point<D, N>, b: &Point<D, N>) -> N {
+        if self.p.is_infinite() {
+            return Chebyshev.distance(a, b);
+        }
+        a.coords
+            .iter()
+            .zip(b.coords.iter())
+            .fold(N::zero(), |acc, (&x, &y)| acc + (x - y).abs().powf(self.p))
+            .powf(self.p.recip())
+    }
+
+    fn axis_distance(&self, a: N, b: N) -> N {
+        (a - b).abs()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integer_metrics() {
+        let p: Point<3, i64> = [1, -2, 3].into();
+        let q: Point<3, i64> = [-1, 2, 8].into();
+        assert_eq!(SquaredEuclidean.distance(&p, &q), 45);
+        assert_eq!(Manhattan.distance(&p, &q), 11);
+        assert_eq!(Chebyshev.distance(&p, &q), 5);
+        assert_eq!(Manhattan.axis_distance(3, -1), 4);
+
+        // Unsigned coordinates never go through a negative difference
+        let p: Point<2, u32> = [5, 1].into();
+        let q: Point<2, u32> = [2, 7].into();
+        assert_eq!(Manhattan.distance(&p, &q), 9);
+        assert_eq!(Chebyshev.distance(&q, &p), 6);
+        assert_eq!(SquaredEuclidean.distance(&p, &q), 45);
+        assert_eq!(SquaredEuclidean.axis_distance(2u32, 5u32), 9);
+    }
+
+    #[test]
+    fn float_metrics() {
+        let p: Point<2, f64> = [0.0, 0.0].into();
+        let q: Point<2, f64> = [3.0, 4.0].into();
+        assert_eq!(Euclidean.distance(&p, &q), 5.0);
+        assert_eq!(Minkowski { p: 1.0 }.distance(&p, &q), 7.0);
+        assert!((Minkowski { p: 2.0 }.distance(&p, &q) - 5.0).abs() < 1e-12);
+        assert_eq!(Minkowski { p: f64::INFINITY }.distance(&p, &q), 4.0);
+        let d3 = Minkowski { p: 3.0 }.distance(&p, &q);
+        assert!(d3 > 4.0 && d3 < 5.0);
+    }
+
+    #[test]
+    fn axis_distance_is_lower_bound() {
+        let p: Point<2, f64> = [1.0, 2.0].into();
+        let q: Point<2, f64> = [-2.0, 6.0].into();
+        fn check<M: Metric<f64, Output = f64>>(m: M, p: &Point<2, f64>, q: &Point<2, f64>) {
+            let d = m.distance(p, q);
+            for axis in 0..2 {
+                assert!(m.axis_distance(p.coords[axis], q.coords[axis]) <= d);
+            }
+        }
+        check(SquaredEuclidean, &p, &q);
+        check(Euclidean, &p, &q);
+        check(Manhattan, &p, &q);
+        check(Chebyshev, &p, &q);
+        check(Minkowski { p: 1.5 }, &p, &q);
+    }
+}
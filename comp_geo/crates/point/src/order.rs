@@ -0,0 +1,161 @@
+use crate::{Coordinate, Point};
+use num_traits::{Float, Num};
+use std::cmp::Ordering;
+
+/// Lexicographic order on float points that is total:
+/// NaN sorts after every number and equals any other NaN. Points with
+/// `Ord` coordinates are already ordered this way by their own `Ord`.
+/// ```
+/// use point::{FloatLexicographic, Point};
+/// let mut points: Vec<Point<2, f64>> = vec![[f64::NAN, 0.0].into(), [1.0, 2.0].into(), [1.0, -1.0].into()];
+/// points.sort_by_key(|&p| FloatLexicographic(p));
+/// assert_eq!(&points[..2], &[[1.0, -1.0].into(), [1.0, 2.0].into()]);
+/// assert!(points[2].coords[0].is_nan());
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct FloatLexicographic<const D: usize, N>(pub Point<D, N>);
+
+fn float_cmp<N: Float>(a: N, b: N) -> Ordering {
+    match (a.is_nan(), b.is_nan()) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        (false, false) => a.partial_cmp(&b).unwrap(),
+    }
+}
+
+impl<const D: usize, N: Float> PartialEq for FloatLexicographic<D, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<const D: usize, N: Float> Eq for FloatLexicographic<D, N> {}
+
+impl<const D: usize, N: Float> PartialOrd for FloatLexicographic<D, N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const D: usize, N: Float> Ord for FloatLexicographic<D, N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0
+            .coords
+            .iter()
+            .zip(other.0.coords.iter())
+            .map(|(&a, &b)| float_cmp(a, b))
+            .find(|o| o.is_ne())
+            .unwrap_or(Ordering::Equal)
+    }
+}
+
+// 0 for the zero vector, 1 for angles in [0, pi), 2 for [pi, 2pi)
+fn half_plane<N: Num + Copy + PartialOrd>(v: &Point<2, N>) -> u8 {
+    let zero = N::zero();
+    if v.x() == zero && v.y() == zero {
+        0
+    } else if v.y() > zero || (v.y() == zero && v.x() > zero) {
+        1
+    } else {
+        2
+    }
+}
+
+/// Orders vectors by their angle counter-clockwise from the positive x axis,
+/// in `[0, 2pi)`. Vectors pointing the same way compare equal and the zero
+/// vector sorts first. Only uses cross products, taken in the wide type as
+/// in `orientation`, so it is exact for every integer vector.
+/// ```
+/// use point::{direction_cmp, Point};
+/// let mut v: Vec<Point<2, i32>> = vec![[0, -1].into(), [-1, 0].into(), [1, 1].into(), [1, 0].into()];
+/// v.sort_by(direction_cmp);
+/// assert_eq!(v, vec![[1, 0].into(), [1, 1].into(), [-1, 0].into(), [0, -1].into()]);
+/// ```
+pub fn direction_cmp<N: Coordinate>(a: &Point<2, N>, b: &Point<2, N>) -> Ordering {
+    wide_direction_cmp(&a.widen(), &b.widen())
+}
+
+/// `direction_cmp` of vectors already in a type their cross product fits.
+fn wide_direction_cmp<W: Coordinate>(a: &Point<2, W>, b: &Point<2, W>) -> Ordering {
+    half_plane(a).cmp(&half_plane(b)).then_with(|| {
+        let cross = a.cross(b);
+        if cross > W::zero() {
+            Ordering::Less
+        } else if cross < W::zero() {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    })
+}
+
+/// Orders points by polar angle around `pivot`, as in `direction_cmp`,
+/// breaking ties between points in the same direction by distance so
+/// that nearer points come first. Suitable for Graham scan and radial sweeps.
+/// Works in the wide type, so like `orientation` it is exact for `i32`
+/// coordinates of magnitude below 2^30 and `i64` below 2^62.
+/// ```
+/// use point::{polar_cmp, Point};
+/// let pivot: Point<2, i32> = [1, 1].into();
+/// let mut v: Vec<Point<2, i32>> = vec![[3, 3].into(), [1, 2].into(), [2, 2].into(), [2, 1].into()];
+/// v.sort_by(|a, b| polar_cmp(&pivot, a, b));
+/// assert_eq!(v, vec![[2, 1].into(), [2, 2].into(), [3, 3].into(), [1, 2].into()]);
+/// ```
+pub fn polar_cmp<N: Coordinate>(pivot: &Point<2, N>, a: &Point<2, N>, b: &Point<2, N>) -> Ordering {
+    let (u, v) = (a.widen() - pivot.widen(), b.widen() - pivot.widen());
+    wide_direction_cmp(&u, &v).then_with(|| u.dot(&u).partial_cmp(&v.dot(&v)).unwrap_or(Ordering::Equal))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lexicographic_btree() {
+        let mut set = std::collections::BTreeSet::new();
+        set.insert(Point { coords: [2, 1, 0] });
+        set.insert(Point { coords: [1, 9, 9] });
+        set.insert(Point { coords: [2, 0, 5] });
+        let sorted: Vec<_> = set.into_iter().map(|p| p.coords).collect();
+        assert_eq!(sorted, vec![[1, 9, 9], [2, 0, 5], [2, 1, 0]]);
+    }
+
+    #[test]
+    fn float_lexicographic_nan() {
+        let a = FloatLexicographic(Point { coords: [1.0, f64::NAN] });
+        let b = FloatLexicographic(Point { coords: [1.0, f64::INFINITY] });
+        assert_eq!(a.cmp(&b), Ordering::Greater);
+        assert_eq!(a, FloatLexicographic(Point { coords: [1.0, f64::NAN] }));
+        assert_eq!(FloatLexicographic(Point { coords: [-0.0, 0.0] }), FloatLexicographic(Point { coords: [0.0, 0.0] }));
+    }
+
+    #[test]
+    fn direction_full_turn() {
+        let dirs: Vec<Point<2, i64>> = vec![
+            [1, 0].into(), [2, 1].into(), [0, 3].into(), [-1, 1].into(),
+            [-5, 0].into(), [-1, -1].into(), [0, -1].into(), [3, -1].into(),
+        ];
+        let mut shuffled = dirs.clone();
+        shuffled.reverse();
+        shuffled.swap(1, 5);
+        shuffled.sort_by(direction_cmp);
+        assert_eq!(shuffled, dirs);
+        assert_eq!(direction_cmp(&[2, 2].into(), &[1, 1].into()), Ordering::Equal);
+        assert_eq!(direction_cmp(&[0, 0].into(), &[1, 0].into()), Ordering::Less);
+        // Cross products past i32
+        assert_eq!(direction_cmp::<i32>(&[100_000, 1].into(), &[1, 100_000].into()), Ordering::Less);
+        assert_eq!(direction_cmp::<i32>(&[i32::MAX, i32::MIN].into(), &[i32::MIN, i32::MIN].into()), Ordering::Greater);
+        let pivot: Point<2, i32> = [-1 << 29, 0].into();
+        let (near, far): (Point<2, i32>, Point<2, i32>) = ([0, 1].into(), [1 << 29, 2].into());
+        assert_eq!(polar_cmp(&pivot, &near, &far), Ordering::Less);
+    }
+
+    #[test]
+    fn polar_floats() {
+        let pivot: Point<2, f64> = [0.0, 0.0].into();
+        let mut v: Vec<Point<2, f64>> = vec![[-1.0, 0.0].into(), [0.0, 0.5].into(), [0.0, 0.25].into(), [1.0, -1e-9].into()];
+        v.sort_by(|a, b| polar_cmp(&pivot, a, b));
+        assert_eq!(v, vec![[0.0, 0.25].into(), [0.0, 0.5].into(), [-1.0, 0.0].into(), [1.0, -1e-9].into()]);
+    }
+}
@@ -0,0 +1,125 @@
+use crate::Point;
+use num_traits::{Float, Num};
+use std::cmp::Ordering;
+
+/// The plane of points `p` with `normal · p == offset`. The normal need not
+/// be unit length, so integer and rational planes stay exact as long as
+/// nothing overflows. Everything is computed in `N`, and the normal is of
+/// degree two in the coordinates and the offset of degree three: for
+/// `i32` that means coordinates of magnitude below about 350. Widen the
+/// points first, e.g. with `Point::widen`, for larger ones.
+/// ```
+/// use point::{Plane, Point};
+/// use std::cmp::Ordering;
+/// let plane = Plane::from_points(&[0, 0, 1].into(), &[1, 0, 1].into(), &[0, 1, 1].into()).unwrap();
+/// assert_eq!(plane, Plane { normal: [0, 0, 1].into(), offset: 1 });
+/// assert_eq!(plane.side(&[5, -3, 4].into()), Ordering::Greater);
+/// assert_eq!(plane.project(&[5, -3, 4].into()), [5, -3, 1].into());
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Plane<N> {
+    pub normal: Point<3, N>,
+    pub offset: N,
+}
+
+impl<N: Num + Copy + PartialOrd> Plane<N> {
+    pub fn from_point_normal(p: &Point<3, N>, normal: Point<3, N>) -> Self {
+        Self { normal, offset: normal.dot(p) }
+    }
+
+    /// The plane through `a`, `b` and `c`, with the normal facing the side
+    /// from which they appear counter-clockwise. `None` if they are collinear.
+    pub fn from_points(a: &Point<3, N>, b: &Point<3, N>, c: &Point<3, N>) -> Option<Self> {
+        let normal = (b - a).cross(&(c - a));
+        if normal.coords.iter().all(|c| c.is_zero()) {
+            return None;
+        }
+        Some(Self::from_point_normal(a, normal))
+    }
+
+    /// `normal · p - offset`: zero on the plane, positive on the side the
+    /// normal faces. Proportional to the signed distance.
+    pub fn evaluate(&self, p: &Point<3, N>) -> N {
+        self.normal.dot(p) - self.offset
+    }
+
+    /// `Greater` on the side the normal faces, `Less` on the other side
+    /// and `Equal` on the plane.
+    pub fn side(&self, p: &Point<3, N>) -> Ordering {
+        self.evaluate(p)
+            .partial_cmp(&N::zero())
+            .unwrap_or(Ordering::Equal)
+    }
+
+    pub fn contains(&self, p: &Point<3, N>) -> bool {
+        self.evaluate(p).is_zero()
+    }
+
+    /// The point on the plane closest to `p`. Exact for rationals and
+    /// other field types; floats round and integer coordinates are
+    /// truncated.
+    pub fn project(&self, p: &Point<3, N>) -> Point<3, N> {
+        let t = self.evaluate(p) / self.normal.dot(&self.normal);
+        Point { coords: std::array::from_fn(|i| p.coords[i] - self.normal.coords[i] * t) }
+    }
+
+    /// The same plane with the normal reversed.
+    pub fn flip(&self) -> Self {
+        Self {
+            normal: Point { coords: self.normal.coords.map(|c| N::zero() - c) },
+            offset: N::zero() - self.offset,
+        }
+    }
+}
+
+impl<N: Float> Plane<N> {
+    pub fn signed_distance(&self, p: &Point<3, N>) -> N {
+        self.evaluate(p) / self.normal.dot(&self.normal).sqrt()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Rational64;
+
+    #[test]
+    fn triple_product() {
+        let a: Point<3, i64> = [1, 0, 0].into();
+        let b: Point<3, i64> = [0, 2, 0].into();
+        let c: Point<3, i64> = [0, 0, 3].into();
+        assert_eq!(a.triple_product(&b, &c), 6);
+        assert_eq!(b.triple_product(&a, &c), -6);
+        assert_eq!(a.triple_product(&b, &(a + b)), 0);
+    }
+
+    #[test]
+    fn sides_and_distance() {
+        let plane = Plane::from_point_normal(&[1.0, 1.0, 1.0].into(), [0.0, 3.0, 4.0].into());
+        let p: Point<3, f64> = [7.0, 4.0, 5.0].into();
+        assert_eq!(plane.signed_distance(&p), 5.0);
+        assert_eq!(plane.side(&p), Ordering::Greater);
+        assert_eq!(plane.flip().side(&p), Ordering::Less);
+        let q = plane.project(&p);
+        assert!(plane.signed_distance(&q).abs() < 1e-12);
+        assert_eq!(plane.side(&[3.0, 5.0, -2.0].into()), Ordering::Equal);
+    }
+
+    #[test]
+    fn exact_projection() {
+        let r = |n| Rational64::from_integer(n);
+        let a = Point { coords: [r(0), r(0), r(0)] };
+        let b = Point { coords: [r(1), r(0), r(1)] };
+        let c = Point { coords: [r(0), r(1), r(0)] };
+        let plane = Plane::from_points(&a, &b, &c).unwrap();
+        let q = plane.project(&Point { coords: [r(1), r(1), r(0)] });
+        assert!(plane.contains(&q));
+        assert_eq!(q, Point { coords: [Rational64::new(1, 2), r(1), Rational64::new(1, 2)] });
+    }
+
+    #[test]
+    fn collinear() {
+        let a: Point<3, i32> = [0, 0, 0].into();
+        assert_eq!(Plane::from_points(&a, &[1, 1, 1].into(), &[2, 2, 2].into()), None);
+    }
+}
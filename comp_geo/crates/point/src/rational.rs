@@ -0,0 +1,449 @@
+use num_traits::{Num, One, PrimInt, Signed, ToPrimitive, Zero};
+use std::cmp::Ordering;
+use std::fmt;
+use std::iter::Sum;
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+/// Exact rational number `numer / denom`.
+///
+/// Values are always kept reduced with a positive denominator, so two
+/// rationals are equal exactly when their fields are equal. Arithmetic
+/// cancels common factors before multiplying and panics if a result still
+/// does not fit in `T`; the `checked_*` methods return `None` instead.
+/// That includes values whose reduced form needs `-T::MIN`, such as
+/// `1 / T::MIN`. Comparisons never overflow.
+/// ```
+/// use point::Rational64;
+/// let a = Rational64::new(1, 3);
+/// let b = Rational64::new(2, 6);
+/// assert_eq!(a, b);
+/// assert_eq!(a + b, Rational64::new(2, 3));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Rational<T> {
+    numer: T,
+    denom: T,
+}
+
+pub type Rational64 = Rational<i64>;
+pub type Rational128 = Rational<i128>;
+
+/// The greatest common divisor, or `T::MIN` when it is `-T::MIN`, which
+/// only happens when both are 0 or `T::MIN` and still divides them
+/// exactly. Works on non-positive values, since unlike positive ones they
+/// can all be negated.
+fn gcd<T: PrimInt + Signed>(a: T, b: T) -> T {
+    let non_positive = |x: T| if x > T::zero() { -x } else { x };
+    let (mut a, mut b) = (non_positive(a), non_positive(b));
+    while !b.is_zero() {
+        // T::MIN % -1 overflows, and every remainder by -1 is zero
+        let r = if b == -T::one() { T::zero() } else { a % b };
+        a = b;
+        b = r;
+    }
+    if a == T::min_value() { a } else { -a }
+}
+
+impl<T: PrimInt + Signed> Rational<T> {
+    /// Panics if `denom` is zero, or with "Rational overflow" if the
+    /// reduced value needs `-T::MIN`.
+    pub fn new(numer: T, denom: T) -> Self {
+        Self::checked_new(numer, denom).expect("Rational overflow")
+    }
+
+    /// As `new`, but `None` if the reduced value needs `-T::MIN`.
+    /// Panics if `denom` is zero.
+    pub fn checked_new(numer: T, denom: T) -> Option<Self> {
+        assert!(!denom.is_zero(), "Rational with zero denominator");
+        let g = gcd(numer, denom);
+        let (numer, denom) = (numer / g, denom / g);
+        if denom < T::zero() {
+            Some(Self { numer: T::zero().checked_sub(&numer)?, denom: T::zero().checked_sub(&denom)? })
+        } else {
+            Some(Self { numer, denom })
+        }
+    }
+
+    pub fn from_integer(n: T) -> Self {
+        Self { numer: n, denom: T::one() }
+    }
+
+    pub fn numer(&self) -> T {
+        self.numer
+    }
+
+    pub fn denom(&self) -> T {
+        self.denom
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.denom.is_one()
+    }
+
+    /// Panics if `self` is zero.
+    pub fn recip(&self) -> Self {
+        Self::new(self.denom, self.numer)
+    }
+
+    pub fn to_f64(&self) -> f64 {
+        // Both parts fit in an f64 for every supported integer type
+        self.numer.to_f64().unwrap() / self.denom.to_f64().unwrap()
+    }
+
+    pub fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        // a/b + c/d = (a*(d/g) + c*(b/g)) / (b/g * d) with g = gcd(b, d),
+        // and the numerator can only share factors with g
+        let g = gcd(self.denom, rhs.denom);
+        let (b, d) = (self.denom / g, rhs.denom / g);
+        let numer = self.numer.checked_mul(&d)?.checked_add(&rhs.numer.checked_mul(&b)?)?;
+        let g2 = gcd(numer, g);
+        Self::checked_new(numer / g2, b.checked_mul(&(rhs.denom / g2))?)
+    }
+
+    pub fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        self.checked_add(&Self { numer: T::zero().checked_sub(&rhs.numer)?, denom: rhs.denom })
+    }
+
+    pub fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+        // Cross reduce first to keep intermediates small
+        let g1 = gcd(self.numer, rhs.denom);
+        let g2 = gcd(rhs.numer, self.denom);
+        Self::checked_new(
+            (self.numer / g1).checked_mul(&(rhs.numer / g2))?,
+            (self.denom / g2).checked_mul(&(rhs.denom / g1))?,
+        )
+    }
+
+    /// Panics on division by zero.
+    pub fn checked_div(&self, rhs: &Self) -> Option<Self> {
+        assert!(!rhs.is_zero(), "Rational division by zero");
+        let g1 = gcd(self.numer, rhs.numer);
+        let g2 = gcd(self.denom, rhs.denom);
+        Self::checked_new(
+            (self.numer / g1).checked_mul(&(rhs.denom / g2))?,
+            (self.denom / g2).checked_mul(&(rhs.numer / g1))?,
+        )
+    }
+
+    pub fn checked_neg(&self) -> Option<Self> {
+        Some(Self { numer: T::zero().checked_sub(&self.numer)?, denom: self.denom })
+    }
+
+    /// Remainder of truncated division, matching the sign convention of
+    /// `%` on integers. Panics on division by zero.
+    pub fn checked_rem(&self, rhs: &Self) -> Option<Self> {
+        assert!(!rhs.is_zero(), "Rational division by zero");
+        // Nearer zero than `rhs` the quotient is zero, however large the
+        // quotient's own denominator would be
+        let bound = if rhs.numer > T::zero() { *rhs } else { rhs.checked_neg()? };
+        if bound.checked_neg()? < *self && *self < bound {
+            return Some(*self);
+        }
+        let q = self.checked_div(rhs)?;
+        let q = Self::from_integer(q.numer / q.denom);
+        self.checked_sub(&rhs.checked_mul(&q)?)
+    }
+
+    /// Integer part rounded down and the non-negative remainder.
+    fn div_floor(numer: T, denom: T) -> (T, T) {
+        let (q, r) = (numer / denom, numer % denom);
+        if r < T::zero() {
+            (q - T::one(), r + denom)
+        } else {
+            (q, r)
+        }
+    }
+}
+
+impl<T: PrimInt + Signed> Default for Rational<T> {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<T: PrimInt + Signed> From<T> for Rational<T> {
+    fn from(n: T) -> Self {
+        Self::from_integer(n)
+    }
+}
+
+impl<T: PrimInt + Signed> Add for Rational<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.checked_add(&rhs).expect("Rational overflow")
+    }
+}
+
+impl<T: PrimInt + Signed> Sub for Rational<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.checked_sub(&rhs).expect("Rational overflow")
+    }
+}
+
+impl<T: PrimInt + Signed> Mul for Rational<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.checked_mul(&rhs).expect("Rational overflow")
+    }
+}
+
+impl<T: PrimInt + Signed> Div for Rational<T> {
+    type Output = Self;
+
+    /// Panics on division by zero.
+    fn div(self, rhs: Self) -> Self::Output {
+        self.checked_div(&rhs).expect("Rational overflow")
+    }
+}
+
+impl<T: PrimInt + Signed> Rem for Rational<T> {
+    type Output = Self;
+
+    /// Remainder of truncated division, matching the sign convention of `%` on integers.
+    fn rem(self, rhs: Self) -> Self::Output {
+        self.checked_rem(&rhs).expect("Rational overflow")
+    }
+}
+
+impl<T: PrimInt + Signed> Neg for Rational<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        self.checked_neg().expect("Rational overflow")
+    }
+}
+
+impl<T: PrimInt + Signed> Zero for Rational<T> {
+    fn zero() -> Self {
+        Self { numer: T::zero(), denom: T::one() }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.numer.is_zero()
+    }
+}
+
+impl<T: PrimInt + Signed> One for Rational<T> {
+    fn one() -> Self {
+        Self { numer: T::one(), denom: T::one() }
+    }
+}
+
+impl<T: PrimInt + Signed> Num for Rational<T> {
+    type FromStrRadixErr = ParseRationalError;
+
+    /// Parses `"n"` or `"n/d"`.
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        let parse = |part: &str| T::from_str_radix(part.trim(), radix).map_err(|_| ParseRationalError);
+        match s.split_once('/') {
+            None => Ok(Self::from_integer(parse(s)?)),
+            Some((n, d)) => {
+                let d = parse(d)?;
+                if d.is_zero() {
+                    return Err(ParseRationalError);
+                }
+                Self::checked_new(parse(n)?, d).ok_or(ParseRationalError)
+            }
+        }
+    }
+}
+
+impl<T: PrimInt + Signed> std::str::FromStr for Rational<T> {
+    type Err = ParseRationalError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_str_radix(s, 10)
+    }
+}
+
+impl<T: PrimInt + Signed> PartialOrd for Rational<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: PrimInt + Signed> Ord for Rational<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Compare integer parts, then the fractional parts by comparing
+        // their reciprocals the other way round: a continued fraction
+        // expansion of both, which never multiplies
+        let (mut a, mut b, mut c, mut d) = (self.numer, self.denom, other.numer, other.denom);
+        let mut reversed = false;
+        let order = loop {
+            let (q1, r1) = Self::div_floor(a, b);
+            let (q2, r2) = Self::div_floor(c, d);
+            if q1 != q2 {
+                break q1.cmp(&q2);
+            }
+            match (r1.is_zero(), r2.is_zero()) {
+                (true, true) => return Ordering::Equal,
+                (true, false) => break Ordering::Less,
+                (false, true) => break Ordering::Greater,
+                (false, false) => {
+                    (a, b, c, d) = (b, r1, d, r2);
+                    reversed = !reversed;
+                }
+            }
+        };
+        if reversed { order.reverse() } else { order }
+    }
+}
+
+impl<T: PrimInt + Signed> Sum for Rational<T> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |acc, x| acc + x)
+    }
+}
+
+impl<T: PrimInt + Signed> ToPrimitive for Rational<T> {
+    fn to_i64(&self) -> Option<i64> {
+        (self.numer / self.denom).to_i64()
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        (self.numer / self.denom).to_u64()
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        Some(Rational::to_f64(self))
+    }
+}
+
+impl<T: PrimInt + Signed + fmt::Display> fmt::Display for Rational<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.numer)
+        } else {
+            write!(f, "{}/{}", self.numer, self.denom)
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseRationalError;
+
+impl fmt::Display for ParseRationalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid rational number")
+    }
+}
+
+impl std::error::Error for ParseRationalError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Point;
+
+    #[test]
+    fn normalizes() {
+        let r = Rational64::new(4, -6);
+        assert_eq!(r.numer(), -2);
+        assert_eq!(r.denom(), 3);
+        assert_eq!(Rational64::new(0, -5), Rational64::zero());
+    }
+
+    #[test]
+    fn arithmetic() {
+        let a = Rational64::new(1, 2);
+        let b = Rational64::new(1, 3);
+        assert_eq!(a + b, Rational64::new(5, 6));
+        assert_eq!(a - b, Rational64::new(1, 6));
+        assert_eq!(a * b, Rational64::new(1, 6));
+        assert_eq!(a / b, Rational64::new(3, 2));
+        assert_eq!(Rational64::new(7, 2) % Rational64::from(2), Rational64::new(3, 2));
+        assert_eq!(-a, Rational64::new(-1, 2));
+    }
+
+    #[test]
+    fn ordering() {
+        let mut v = vec![Rational64::new(1, 2), Rational64::new(-1, 3), Rational64::new(1, 3)];
+        v.sort();
+        assert_eq!(v, vec![Rational64::new(-1, 3), Rational64::new(1, 3), Rational64::new(1, 2)]);
+    }
+
+    #[test]
+    fn large_values() {
+        // 1 + 1/(MAX - 1) against 1 + 1/(MAX - 2)
+        let big = Rational64::new(i64::MAX, i64::MAX - 1);
+        let bigger = Rational64::new(i64::MAX - 1, i64::MAX - 2);
+        assert!(big < bigger);
+        assert!(Rational64::new(-i64::MAX, 3) < Rational64::new(-i64::MAX, 5));
+        assert_eq!(Rational64::new(i64::MAX, 7).cmp(&Rational64::new(i64::MAX, 7)), Ordering::Equal);
+        assert_eq!(big.checked_mul(&big), None);
+        assert_eq!(big.checked_mul(&bigger), Some(Rational64::new(i64::MAX, i64::MAX - 2)));
+        assert_eq!(big.checked_add(&bigger), None);
+        assert_eq!(Rational64::from(i64::MAX).checked_sub(&Rational64::from(-1)), None);
+        // Common factors cancel before anything is multiplied
+        let half = Rational64::new(1, 2);
+        assert_eq!(Rational64::new(i64::MAX - 1, 3) * half, Rational64::new((i64::MAX - 1) / 2, 3));
+        assert_eq!(Rational64::new(1, 1 << 62) + Rational64::new(1, 1 << 62), Rational64::new(1, 1 << 61));
+    }
+
+    #[test]
+    fn min_values() {
+        let min = i64::MIN;
+        assert_eq!(Rational64::new(min, min), Rational64::one());
+        assert_eq!(Rational64::new(0, min), Rational64::zero());
+        assert_eq!(Rational64::new(min, 2), Rational64::from(min / 2));
+        assert_eq!(Rational64::new(2, min), Rational64::new(-1, 1 << 62));
+        assert_eq!(Rational64::new(min, -2), Rational64::from(1 << 62));
+        assert_eq!(Rational64::checked_new(1, min), None);
+        assert_eq!(Rational64::checked_new(min, -1), None);
+        assert_eq!(Rational64::from(min).checked_neg(), None);
+        assert_eq!(Rational64::from(min) / Rational64::from(min), Rational64::one());
+        assert_eq!(Rational64::from(min) * Rational64::new(1, -2), Rational64::from(1 << 62));
+        assert_eq!(Rational64::from(min).checked_div(&Rational64::from(-1)), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Rational overflow")]
+    fn new_min_denominator_panics() {
+        let _ = Rational64::new(1, i64::MIN);
+    }
+
+    #[test]
+    #[should_panic(expected = "Rational overflow")]
+    fn neg_min_panics() {
+        let _ = -Rational64::from(i64::MIN);
+    }
+
+    #[test]
+    fn remainders() {
+        let r = Rational64::new;
+        assert_eq!(r(7, 3) % r(i64::MAX / 2, 5), r(7, 3));
+        assert_eq!(r(-7, 3) % r(-(i64::MAX / 2), 5), r(-7, 3));
+        assert_eq!(r(-7, 2) % r(2, 1), r(-3, 2));
+        assert_eq!(r(7, 2) % r(-2, 1), r(3, 2));
+        assert_eq!(r(5, 6) % r(1, 4), r(1, 12));
+        assert_eq!(r(1, 2) % r(1, 2), Rational64::zero());
+        assert_eq!(Rational64::from(i64::MAX).checked_rem(&r(1, i64::MAX)), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Rational overflow")]
+    fn overflow_panics() {
+        let _ = Rational64::from(i64::MAX) * Rational64::from(2);
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!("3/-6".parse::<Rational64>(), Ok(Rational64::new(-1, 2)));
+        assert_eq!("7".parse::<Rational128>(), Ok(Rational128::from(7)));
+        assert_eq!("1/0".parse::<Rational64>(), Err(ParseRationalError));
+        assert_eq!("1/-9223372036854775808".parse::<Rational64>(), Err(ParseRationalError));
+        assert_eq!(Rational64::new(-3, 4).to_string(), "-3/4");
+    }
+
+    #[test]
+    fn as_point_coordinate() {
+        let p1: Point<2, Rational64> = [Rational64::new(1, 2), Rational64::from(0)].into();
+        let p2: Point<2, Rational64> = [Rational64::from(0), Rational64::new(1, 2)].into();
+        assert_eq!(p1.square_distance(&p2), Rational64::new(1, 2));
+        assert_eq!(p1.cross(&p2), Rational64::new(1, 4));
+    }
+}
@@ -0,0 +1,91 @@
+use crate::{Point, Rational};
+use num_traits::{PrimInt, Signed};
+use serde::de::{self, Deserialize, Deserializer, SeqAccess, Visitor};
+use serde::ser::{Serialize, SerializeTuple, Serializer};
+use std::fmt;
+use std::marker::PhantomData;
+
+/// Points are written as a fixed size array of coordinates, e.g. `[1, 2]`.
+impl<const D: usize, N: Serialize> Serialize for Point<D, N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut tuple = serializer.serialize_tuple(D)?;
+        for c in &self.coords {
+            tuple.serialize_element(c)?;
+        }
+        tuple.end()
+    }
+}
+
+struct PointVisitor<const D: usize, N>(PhantomData<N>);
+
+impl<'de, const D: usize, N: Deserialize<'de>> Visitor<'de> for PointVisitor<D, N> {
+    type Value = Point<D, N>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "an array of {D} coordinates")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut coords = Vec::with_capacity(D);
+        for i in 0..D {
+            coords.push(seq.next_element()?.ok_or_else(|| de::Error::invalid_length(i, &self))?);
+        }
+        if seq.next_element::<de::IgnoredAny>()?.is_some() {
+            return Err(de::Error::invalid_length(D + 1, &self));
+        }
+        Ok(Point { coords: coords.try_into().unwrap_or_else(|_| unreachable!()) })
+    }
+}
+
+impl<'de, const D: usize, N: Deserialize<'de>> Deserialize<'de> for Point<D, N> {
+    fn deserialize<De: Deserializer<'de>>(deserializer: De) -> Result<Self, De::Error> {
+        deserializer.deserialize_tuple(D, PointVisitor(PhantomData))
+    }
+}
+
+/// Rationals are written as a `[numer, denom]` pair.
+impl<T: PrimInt + Signed + Serialize> Serialize for Rational<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (self.numer(), self.denom()).serialize(serializer)
+    }
+}
+
+impl<'de, T: PrimInt + Signed + Deserialize<'de>> Deserialize<'de> for Rational<T> {
+    fn deserialize<De: Deserializer<'de>>(deserializer: De) -> Result<Self, De::Error> {
+        let (numer, denom) = <(T, T)>::deserialize(deserializer)?;
+        if denom.is_zero() {
+            return Err(de::Error::custom("rational with zero denominator"));
+        }
+        // `new` panics when the reduced value needs -T::MIN, as for
+        // [1, T::MIN]; untrusted input gets an error instead
+        Rational::checked_new(numer, denom).ok_or_else(|| de::Error::custom("rational out of range"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Point, Rational64};
+
+    #[test]
+    fn point_json() {
+        let p: Point<3, i32> = [1, -2, 3].into();
+        let json = serde_json::to_string(&p).unwrap();
+        assert_eq!(json, "[1,-2,3]");
+        assert_eq!(serde_json::from_str::<Point<3, i32>>(&json).unwrap(), p);
+        assert!(serde_json::from_str::<Point<3, i32>>("[1,2]").is_err());
+        assert!(serde_json::from_str::<Point<2, i32>>("[1,2,3]").is_err());
+    }
+
+    #[test]
+    fn rational_json() {
+        let r = Rational64::new(2, -4);
+        assert_eq!(serde_json::to_string(&r).unwrap(), "[-1,2]");
+        assert_eq!(serde_json::from_str::<Rational64>("[3,6]").unwrap(), Rational64::new(1, 2));
+        assert!(serde_json::from_str::<Rational64>("[3,0]").is_err());
+        // Values that would need -i64::MIN are errors, not panics
+        assert!(serde_json::from_str::<Rational64>("[1,-9223372036854775808]").is_err());
+        assert!(serde_json::from_str::<Rational64>("[-9223372036854775808,-1]").is_err());
+        let half_min = serde_json::from_str::<Rational64>("[-9223372036854775808,-2]").unwrap();
+        assert_eq!(half_min, Rational64::from(1 << 62));
+    }
+}
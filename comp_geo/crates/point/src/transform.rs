@@ -0,0 +1,395 @@
+use crate::Point;
+use num_traits::{Float, Num};
+use std::ops::Mul;
+
+/// Affine transform of the plane, stored as the top two rows of a
+/// 3x3 matrix acting on column vectors `[x, y, 1]`.
+/// ```
+/// use point::{Point, Transform2};
+/// let t = Transform2::translation(1, 2).then(&Transform2::scaling(2, 2));
+/// assert_eq!(t.apply(&Point { coords: [1, 1] }), Point { coords: [4, 6] });
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Transform2<N> {
+    pub m: [[N; 3]; 2],
+}
+
+/// Affine transform of space, stored as the top three rows of a
+/// 4x4 matrix acting on column vectors `[x, y, z, 1]`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Transform3<N> {
+    pub m: [[N; 4]; 3],
+}
+
+/// Projective transform of the plane as a full 3x3 matrix
+/// acting on homogeneous coordinates `[x, y, w]`.
+/// ```
+/// use point::{Homogeneous, Point};
+/// let h = Homogeneous { m: [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [1.0, 0.0, 1.0]] };
+/// assert_eq!(h.apply(&Point { coords: [1.0, 4.0] }), Some(Point { coords: [0.5, 2.0] }));
+/// assert_eq!(h.apply(&Point { coords: [-1.0, 4.0] }), None);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Homogeneous<N> {
+    pub m: [[N; 3]; 3],
+}
+
+fn det3<N: Num + Copy>(m: &[[N; 3]; 3]) -> N {
+    m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+        - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+        + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+}
+
+/// Inverse of a 3x3 matrix via the adjugate. For integer types
+/// the result is only returned when it is exact.
+fn inverse3<N: Num + Copy>(m: &[[N; 3]; 3]) -> Option<[[N; 3]; 3]> {
+    let det = det3(m);
+    if det == N::zero() {
+        return None;
+    }
+    let cof = |r0: usize, r1: usize, c0: usize, c1: usize| m[r0][c0] * m[r1][c1] - m[r0][c1] * m[r1][c0];
+    let adj = [
+        [cof(1, 2, 1, 2), cof(0, 2, 2, 1), cof(0, 1, 1, 2)],
+        [cof(1, 2, 2, 0), cof(0, 2, 0, 2), cof(0, 1, 2, 0)],
+        [cof(1, 2, 0, 1), cof(0, 2, 1, 0), cof(0, 1, 0, 1)],
+    ];
+    // Integer division truncates, so every entry must divide evenly
+    let integral = N::one() / (N::one() + N::one()) == N::zero();
+    if integral && adj.iter().flatten().any(|&v| v % det != N::zero()) {
+        return None;
+    }
+    Some(adj.map(|row| row.map(|v| v / det)))
+}
+
+fn mul3<N: Num + Copy>(a: &[[N; 3]; 3], b: &[[N; 3]; 3]) -> [[N; 3]; 3] {
+    std::array::from_fn(|i| {
+        std::array::from_fn(|j| (0..3).fold(N::zero(), |acc, k| acc + a[i][k] * b[k][j]))
+    })
+}
+
+fn identity3<N: Num + Copy>() -> [[N; 3]; 3] {
+    std::array::from_fn(|i| std::array::from_fn(|j| if i == j { N::one() } else { N::zero() }))
+}
+
+impl<N: Num + Copy> Transform2<N> {
+    pub fn identity() -> Self {
+        let i = identity3();
+        Self { m: [i[0], i[1]] }
+    }
+
+    pub fn translation(dx: N, dy: N) -> Self {
+        let (o, z) = (N::one(), N::zero());
+        Self { m: [[o, z, dx], [z, o, dy]] }
+    }
+
+    pub fn scaling(sx: N, sy: N) -> Self {
+        let z = N::zero();
+        Self { m: [[sx, z, z], [z, sy, z]] }
+    }
+
+    /// `x' = x + shx * y`, `y' = y + shy * x`
+    pub fn shear(shx: N, shy: N) -> Self {
+        let (o, z) = (N::one(), N::zero());
+        Self { m: [[o, shx, z], [shy, o, z]] }
+    }
+
+    pub fn apply(&self, p: &Point<2, N>) -> Point<2, N> {
+        let m = &self.m;
+        Point {
+            coords: [
+                m[0][0] * p.x() + m[0][1] * p.y() + m[0][2],
+                m[1][0] * p.x() + m[1][1] * p.y() + m[1][2],
+            ],
+        }
+    }
+
+    /// Applies only the linear part, so translations leave vectors unchanged.
+    pub fn apply_vector(&self, v: &Point<2, N>) -> Point<2, N> {
+        let m = &self.m;
+        Point {
+            coords: [m[0][0] * v.x() + m[0][1] * v.y(), m[1][0] * v.x() + m[1][1] * v.y()],
+        }
+    }
+
+    /// The transform that applies `self` first and then `next`.
+    pub fn then(&self, next: &Self) -> Self {
+        *next * *self
+    }
+
+    pub fn determinant(&self) -> N {
+        self.m[0][0] * self.m[1][1] - self.m[0][1] * self.m[1][0]
+    }
+
+    pub fn inverse(&self) -> Option<Self> {
+        let inv = inverse3(&self.to_matrix())?;
+        Some(Self { m: [inv[0], inv[1]] })
+    }
+
+    fn to_matrix(self) -> [[N; 3]; 3] {
+        let (o, z) = (N::one(), N::zero());
+        [self.m[0], self.m[1], [z, z, o]]
+    }
+}
+
+impl<N: Float> Transform2<N> {
+    /// Counter-clockwise rotation about the origin by `angle` radians.
+    pub fn rotation(angle: N) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        let z = N::zero();
+        Self { m: [[cos, -sin, z], [sin, cos, z]] }
+    }
+
+    pub fn rotation_about(center: &Point<2, N>, angle: N) -> Self {
+        Self::translation(-center.x(), -center.y())
+            .then(&Self::rotation(angle))
+            .then(&Self::translation(center.x(), center.y()))
+    }
+}
+
+/// `(a * b).apply(p) == a.apply(&b.apply(p))`
+impl<N: Num + Copy> Mul for Transform2<N> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let m = mul3(&self.to_matrix(), &rhs.to_matrix());
+        Self { m: [m[0], m[1]] }
+    }
+}
+
+impl<N: Num + Copy> Transform3<N> {
+    pub fn identity() -> Self {
+        Self::from_linear(identity3(), [N::zero(); 3])
+    }
+
+    pub fn translation(dx: N, dy: N, dz: N) -> Self {
+        Self::from_linear(identity3(), [dx, dy, dz])
+    }
+
+    pub fn scaling(sx: N, sy: N, sz: N) -> Self {
+        let z = N::zero();
+        Self::from_linear([[sx, z, z], [z, sy, z], [z, z, sz]], [z; 3])
+    }
+
+    /// Builds a transform `p -> linear * p + offset`.
+    pub fn from_linear(linear: [[N; 3]; 3], offset: [N; 3]) -> Self {
+        Self { m: std::array::from_fn(|i| [linear[i][0], linear[i][1], linear[i][2], offset[i]]) }
+    }
+
+    pub fn apply(&self, p: &Point<3, N>) -> Point<3, N> {
+        Point {
+            coords: std::array::from_fn(|i| {
+                let r = &self.m[i];
+                r[0] * p.x() + r[1] * p.y() + r[2] * p.z() + r[3]
+            }),
+        }
+    }
+
+    pub fn apply_vector(&self, v: &Point<3, N>) -> Point<3, N> {
+        Point {
+            coords: std::array::from_fn(|i| {
+                let r = &self.m[i];
+                r[0] * v.x() + r[1] * v.y() + r[2] * v.z()
+            }),
+        }
+    }
+
+    pub fn then(&self, next: &Self) -> Self {
+        *next * *self
+    }
+
+    pub fn determinant(&self) -> N {
+        det3(&self.linear())
+    }
+
+    pub fn inverse(&self) -> Option<Self> {
+        // [A | b]^-1 = [A^-1 | -A^-1 b]
+        let inv = inverse3(&self.linear())?;
+        let offset = std::array::from_fn(|i| {
+            N::zero() - (0..3).fold(N::zero(), |acc, k| acc + inv[i][k] * self.m[k][3])
+        });
+        Some(Self::from_linear(inv, offset))
+    }
+
+    fn linear(&self) -> [[N; 3]; 3] {
+        self.m.map(|r| [r[0], r[1], r[2]])
+    }
+}
+
+impl<N: Float> Transform3<N> {
+    /// Rotation by `angle` radians about the x axis, counter-clockwise looking down the axis.
+    pub fn rotation_x(angle: N) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        let (o, z) = (N::one(), N::zero());
+        Self::from_linear([[o, z, z], [z, cos, -sin], [z, sin, cos]], [z; 3])
+    }
+
+    pub fn rotation_y(angle: N) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        let (o, z) = (N::one(), N::zero());
+        Self::from_linear([[cos, z, sin], [z, o, z], [-sin, z, cos]], [z; 3])
+    }
+
+    pub fn rotation_z(angle: N) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        let (o, z) = (N::one(), N::zero());
+        Self::from_linear([[cos, -sin, z], [sin, cos, z], [z, z, o]], [z; 3])
+    }
+}
+
+impl<N: Num + Copy> Mul for Transform3<N> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let linear = mul3(&self.linear(), &rhs.linear());
+        let offset = std::array::from_fn(|i| {
+            (0..3).fold(self.m[i][3], |acc, k| acc + self.m[i][k] * rhs.m[k][3])
+        });
+        Self::from_linear(linear, offset)
+    }
+}
+
+impl<N: Num + Copy> Homogeneous<N> {
+    pub fn identity() -> Self {
+        Self { m: identity3() }
+    }
+
+    /// Maps a point given in homogeneous coordinates.
+    pub fn apply_homogeneous(&self, p: &Point<3, N>) -> Point<3, N> {
+        Point {
+            coords: std::array::from_fn(|i| {
+                let r = &self.m[i];
+                r[0] * p.x() + r[1] * p.y() + r[2] * p.z()
+            }),
+        }
+    }
+
+    /// Maps a point of the plane, returning `None` when it is sent to
+    /// infinity or, for integer types, to a point off the integer grid.
+    pub fn apply(&self, p: &Point<2, N>) -> Option<Point<2, N>> {
+        self.apply_homogeneous(&p.to_homogeneous()).from_homogeneous()
+    }
+
+    pub fn then(&self, next: &Self) -> Self {
+        *next * *self
+    }
+
+    pub fn determinant(&self) -> N {
+        det3(&self.m)
+    }
+
+    pub fn inverse(&self) -> Option<Self> {
+        inverse3(&self.m).map(|m| Self { m })
+    }
+}
+
+impl<N: Num + Copy> Mul for Homogeneous<N> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self { m: mul3(&self.m, &rhs.m) }
+    }
+}
+
+impl<N: Num + Copy> From<Transform2<N>> for Homogeneous<N> {
+    fn from(t: Transform2<N>) -> Self {
+        Self { m: t.to_matrix() }
+    }
+}
+
+impl<N: Num + Copy> Point<2, N> {
+    /// `[x, y]` as `[x, y, 1]`
+    pub fn to_homogeneous(&self) -> Point<3, N> {
+        Point { coords: [self.x(), self.y(), N::one()] }
+    }
+}
+
+impl<N: Num + Copy> Point<3, N> {
+    /// `[x, y, w]` as `[x / w, y / w]`, or `None` for a point at infinity.
+    /// Like `inverse`, integer types give `None` rather than truncating
+    /// when `w` does not divide `x` and `y`.
+    pub fn from_homogeneous(&self) -> Option<Point<2, N>> {
+        let w = self.z();
+        if w == N::zero() {
+            return None;
+        }
+        let integral = N::one() / (N::one() + N::one()) == N::zero();
+        if integral && (self.x() % w != N::zero() || self.y() % w != N::zero()) {
+            return None;
+        }
+        Some(Point { coords: [self.x() / w, self.y() / w] })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f64::consts::FRAC_PI_2;
+
+    fn close<const D: usize>(a: Point<D, f64>, b: Point<D, f64>) -> bool {
+        a.square_distance(&b) < 1e-18
+    }
+
+    #[test]
+    fn compose_order() {
+        let p: Point<2, i32> = [1, 0].into();
+        let t = Transform2::scaling(3, 3).then(&Transform2::translation(1, 1));
+        assert_eq!(t.apply(&p), [4, 1].into());
+        let t = Transform2::translation(1, 1).then(&Transform2::scaling(3, 3));
+        assert_eq!(t.apply(&p), [6, 3].into());
+    }
+
+    #[test]
+    fn shear() {
+        let t = Transform2::shear(2, 0);
+        assert_eq!(t.apply(&[1, 1].into()), [3, 1].into());
+        assert_eq!(t.inverse().unwrap().apply(&[3, 1].into()), [1, 1].into());
+    }
+
+    #[test]
+    fn integer_inverse() {
+        assert!(Transform2::scaling(2, 2).inverse().is_none());
+        assert!(Transform2::scaling(0, 1).inverse().is_none());
+        let t = Transform2::translation(5, -3);
+        assert_eq!(t.inverse(), Some(Transform2::translation(-5, 3)));
+    }
+
+    #[test]
+    fn rotation() {
+        let t = Transform2::rotation_about(&[1.0, 1.0].into(), FRAC_PI_2);
+        assert!(close(t.apply(&[2.0, 1.0].into()), [1.0, 2.0].into()));
+        let back = t.inverse().unwrap();
+        assert!(close(back.apply(&[1.0, 2.0].into()), [2.0, 1.0].into()));
+    }
+
+    #[test]
+    fn transform3() {
+        let t = Transform3::rotation_z(FRAC_PI_2).then(&Transform3::translation(0.0, 0.0, 1.0));
+        assert!(close(t.apply(&[1.0, 0.0, 0.0].into()), [0.0, 1.0, 1.0].into()));
+        assert!(close(t.apply_vector(&[1.0, 0.0, 0.0].into()), [0.0, 1.0, 0.0].into()));
+        let inv = t.inverse().unwrap();
+        assert!(close(inv.apply(&[0.0, 1.0, 1.0].into()), [1.0, 0.0, 0.0].into()));
+        let s = Transform3::scaling(2, 3, 4);
+        assert_eq!(s.determinant(), 24);
+        assert_eq!(s.apply(&[1, 1, 1].into()), [2, 3, 4].into());
+    }
+
+    #[test]
+    fn homogeneous() {
+        let affine = Transform2::translation(2.0, 0.0);
+        let h = Homogeneous::from(affine);
+        assert_eq!(h.apply(&[1.0, 1.0].into()), Some([3.0, 1.0].into()));
+
+        // perspective divide by x
+        let persp = Homogeneous { m: [[0.0, 1.0, 0.0], [0.0, 0.0, 1.0], [1.0, 0.0, 0.0]] };
+        assert_eq!(persp.apply(&[2.0, 4.0].into()), Some([2.0, 0.5].into()));
+        let composed = h.then(&persp);
+        assert_eq!(composed.apply(&[0.0, 4.0].into()), Some([2.0, 0.5].into()));
+        let inv = persp.inverse().unwrap();
+        assert_eq!(inv.apply(&[2.0, 0.5].into()), Some([2.0, 4.0].into()));
+        // Integer points only map to integer points
+        let h = Homogeneous { m: [[1, 0, 0], [0, 1, 0], [1, 0, 1]] };
+        assert_eq!(h.apply(&[1, 4].into()), None);
+        assert_eq!(h.apply(&[-2, 4].into()), Some([2, -4].into()));
+        assert_eq!(h.apply(&[-1, 4].into()), None);
+    }
+}
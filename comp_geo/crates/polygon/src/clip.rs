@@ -0,0 +1,133 @@
+use crate::holes::to_field;
+use crate::Polygon;
+use point::{orientation, Aabb, Coordinate, Point};
+use std::cmp::Ordering;
+
+impl<N: Coordinate> Polygon<N> {
+    /// The part of the polygon inside the convex polygon `window`, by
+    /// Sutherland–Hodgman clipping. The window may be either orientation and
+    /// the result keeps the orientation of `self`. Exact for integer
+    /// coordinates. Empty when they do not overlap or the window has no area.
+    ///
+    /// A concave polygon that the window cuts into several pieces comes
+    /// back as a single ring, with the pieces joined by edges running along
+    /// the window boundary and back.
+    /// ```
+    /// use point::Rational128;
+    /// use polygon::Polygon;
+    /// let triangle: Polygon<i32> = vec![[0, 0].into(), [4, 0].into(), [0, 4].into()].into();
+    /// let window: Polygon<i32> = vec![[1, 1].into(), [5, 1].into(), [5, 5].into(), [1, 5].into()].into();
+    /// let clipped = triangle.clip_convex(&window);
+    /// let expected: Vec<_> = [[1, 1], [3, 1], [1, 3]].map(|p| p.map(Rational128::from).into()).into();
+    /// assert_eq!(clipped.vertices, expected);
+    /// ```
+    pub fn clip_convex(&self, window: &Polygon<N>) -> Polygon<N::Field> {
+        let mut window = to_field(window);
+        match window.orientation() {
+            Ordering::Equal => return Polygon::from(Vec::new()),
+            Ordering::Less => window.reverse(),
+            Ordering::Greater => {}
+        }
+        let mut output = to_field(self).vertices;
+        for edge in window.edges() {
+            let input = std::mem::take(&mut output);
+            let inside = |p: &Point<2, N::Field>| orientation(&edge.start, &edge.end, p) != Ordering::Less;
+            let n = input.len();
+            for i in 0..n {
+                let (prev, cur) = (input[(i + n - 1) % n], input[i]);
+                match (inside(&prev), inside(&cur)) {
+                    (true, true) => output.push(cur),
+                    (false, true) => {
+                        output.push(crossing(&prev, &cur, &edge.start, &edge.end));
+                        output.push(cur);
+                    }
+                    (true, false) => output.push(crossing(&prev, &cur, &edge.start, &edge.end)),
+                    (false, false) => {}
+                }
+            }
+            // Points on the window edge can appear twice in a row
+            output.dedup();
+            if output.len() > 1 && output.first() == output.last() {
+                output.pop();
+            }
+        }
+        Polygon::from(output)
+    }
+
+    /// The part of the polygon inside the closed box `window`.
+    /// ```
+    /// use point::Aabb;
+    /// use polygon::Polygon;
+    /// let square: Polygon<f64> = vec![[-1.0, -1.0].into(), [1.0, -1.0].into(), [1.0, 1.0].into(), [-1.0, 1.0].into()].into();
+    /// let quarter = square.clip_to_box(&Aabb::new([0.0, 0.0].into(), [5.0, 5.0].into()));
+    /// assert_eq!(quarter.area(), 1.0);
+    /// ```
+    pub fn clip_to_box(&self, window: &Aabb<2, N>) -> Polygon<N::Field> {
+        let (lo, hi) = (window.min, window.max);
+        let corners = vec![lo, [hi.x(), lo.y()].into(), hi, [lo.x(), hi.y()].into()];
+        self.clip_convex(&Polygon::from(corners))
+    }
+}
+
+/// Where the segment `p`–`q` meets the line through `a` and `b`. The
+/// segment must not be parallel to the line.
+fn crossing<F: Coordinate<Field = F>>(p: &Point<2, F>, q: &Point<2, F>, a: &Point<2, F>, b: &Point<2, F>) -> Point<2, F> {
+    let d = *b - *a;
+    let t = d.cross(&(*p - *a)) / d.cross(&(*p - *q));
+    Point { coords: std::array::from_fn(|i| p.coords[i] + (q.coords[i] - p.coords[i]) * t) }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::polygon;
+    use crate::{MultiPolygon, PolygonWithHoles};
+    use point::{Aabb, Rational128};
+    use std::cmp::Ordering;
+
+    #[test]
+    fn convex_window() {
+        let square = polygon(&[[0, 0], [4, 0], [4, 4], [0, 4]]);
+        let diamond = polygon(&[[2, -1], [5, 2], [2, 5], [-1, 2]]);
+        let clipped = square.clip_convex(&diamond);
+        assert_eq!(clipped.len(), 8);
+        assert_eq!(clipped.area(), 16.0 - 4.0 * 0.5);
+        assert_eq!(clipped.orientation(), Ordering::Greater);
+
+        // A clockwise window clips the same way
+        let mut clockwise = diamond.clone();
+        clockwise.reverse();
+        assert_eq!(square.clip_convex(&clockwise), clipped);
+
+        // Fully inside and fully outside
+        let window = polygon(&[[-10, -10], [10, -10], [10, 10], [-10, 10]]);
+        assert_eq!(square.clip_convex(&window).area(), 16.0);
+        assert!(square.clip_convex(&polygon(&[[5, 5], [6, 5], [6, 6]])).is_empty());
+        assert!(square.clip_convex(&polygon(&[[1, 1], [2, 2], [3, 3]])).is_empty());
+    }
+
+    #[test]
+    fn concave_subject() {
+        // A U shape whose arms are cut off from each other by the window
+        let u = polygon(&[[0, 0], [6, 0], [6, 6], [4, 6], [4, 2], [2, 2], [2, 6], [0, 6]]);
+        let window = Aabb::new([1, 3].into(), [5, 7].into());
+        let clipped = u.clip_to_box(&window);
+        assert_eq!(clipped.area(), 6.0);
+        // Same area as the exact intersection, which splits the arms apart
+        let as_region = |p| MultiPolygon::from(PolygonWithHoles::from(p));
+        let exact = as_region(u.clone()).intersection(&as_region(polygon(&[[1, 3], [5, 3], [5, 7], [1, 7]])));
+        assert_eq!(exact.polygons.len(), 2);
+        assert_eq!(exact.area(), clipped.area());
+    }
+
+    #[test]
+    fn fractional_crossings() {
+        let triangle = polygon(&[[0, 0], [3, 0], [0, 3]]);
+        let clipped = triangle.clip_to_box(&Aabb::new([0, 0].into(), [2, 2].into()));
+        let r = |n, d| Rational128::new(n, d);
+        assert_eq!(clipped.area(), 4.0 - 0.5);
+        assert!(clipped.vertices.contains(&[r(2, 1), r(1, 1)].into()));
+        let thin = polygon(&[[0, 0], [3, 1], [0, 2]]).clip_to_box(&Aabb::new([1, 0].into(), [2, 2].into()));
+        assert!(thin.vertices.contains(&[r(2, 1), r(2, 3)].into()));
+        assert_eq!(thin.area(), 1.0);
+    }
+}
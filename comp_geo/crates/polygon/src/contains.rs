@@ -0,0 +1,152 @@
+use crate::Polygon;
+use point::{orientation, Coordinate, Point};
+use std::cmp::Ordering;
+
+/// Where a point lies relative to a polygon.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Containment {
+    Inside,
+    Boundary,
+    Outside,
+}
+
+/// Point-in-polygon tests. Both rules only compare coordinates and the
+/// signs of cross products, so they are exact for integer coordinates,
+/// including for points on an edge or vertex, which are reported as
+/// `Boundary` by either rule.
+/// ```
+/// use polygon::{Containment, Polygon};
+/// let l_shape: Polygon<i32> = vec![[0, 0].into(), [4, 0].into(), [4, 1].into(), [1, 1].into(), [1, 3].into(), [0, 3].into()].into();
+/// assert_eq!(l_shape.locate(&[3, 0].into()), Containment::Boundary);
+/// assert_eq!(l_shape.locate(&[2, 2].into()), Containment::Outside);
+/// assert!(l_shape.contains(&[0, 2].into()));
+/// ```
+impl<N: Coordinate> Polygon<N> {
+    pub fn on_boundary(&self, p: &Point<2, N>) -> bool {
+        self.edges().any(|e| {
+            orientation(&e.start, &e.end, p) == Ordering::Equal
+                && (0..2).all(|i| {
+                    let (lo, hi) = if e.start.coords[i] < e.end.coords[i] {
+                        (e.start.coords[i], e.end.coords[i])
+                    } else {
+                        (e.end.coords[i], e.start.coords[i])
+                    };
+                    lo <= p.coords[i] && p.coords[i] <= hi
+                })
+        })
+    }
+
+    /// How many times the boundary winds counter-clockwise around `p`.
+    /// Meaningless for points on the boundary.
+    pub fn winding_number(&self, p: &Point<2, N>) -> i32 {
+        let mut winding = 0;
+        for e in self.edges() {
+            let (a, b) = (e.start, e.end);
+            if a.y() <= p.y() {
+                if b.y() > p.y() && orientation(&a, &b, p) == Ordering::Greater {
+                    winding += 1;
+                }
+            } else if b.y() <= p.y() && orientation(&a, &b, p) == Ordering::Less {
+                winding -= 1;
+            }
+        }
+        winding
+    }
+
+    /// How many edges a ray from `p` in the `+x` direction crosses.
+    /// Vertices on the ray count for the edges above it only, so the
+    /// ray is never counted twice at a vertex.
+    pub fn crossing_number(&self, p: &Point<2, N>) -> usize {
+        self.edges()
+            .filter(|e| {
+                let (a, b) = (e.start, e.end);
+                if (a.y() > p.y()) == (b.y() > p.y()) {
+                    return false;
+                }
+                // The edge straddles the ray; it crosses if p is left of the upward edge
+                let o = orientation(&a, &b, p);
+                if b.y() > a.y() { o == Ordering::Greater } else { o == Ordering::Less }
+            })
+            .count()
+    }
+
+    /// Locates `p` by the nonzero winding rule. Agrees with
+    /// `locate_even_odd` for simple polygons.
+    pub fn locate(&self, p: &Point<2, N>) -> Containment {
+        if self.on_boundary(p) {
+            Containment::Boundary
+        } else if self.winding_number(p) != 0 {
+            Containment::Inside
+        } else {
+            Containment::Outside
+        }
+    }
+
+    /// Locates `p` by the even-odd (crossing number) rule.
+    pub fn locate_even_odd(&self, p: &Point<2, N>) -> Containment {
+        if self.on_boundary(p) {
+            Containment::Boundary
+        } else if self.crossing_number(p) % 2 == 1 {
+            Containment::Inside
+        } else {
+            Containment::Outside
+        }
+    }
+
+    /// Whether `p` is inside or on the boundary, by the winding rule.
+    pub fn contains(&self, p: &Point<2, N>) -> bool {
+        self.locate(p) != Containment::Outside
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::polygon;
+
+    #[test]
+    fn rules_agree_on_simple_polygons() {
+        let shapes = [
+            polygon(&[[0, 0], [4, 0], [4, 1], [1, 1], [1, 3], [0, 3]]),
+            polygon(&[[0, 0], [0, 4], [2, 2], [4, 4], [4, 0]]),
+            polygon(&[[0, 0], [4, 0], [4, 4], [2, 2], [0, 4]]),
+        ];
+        for shape in &shapes {
+            for x in -1..=5 {
+                for y in -1..=5 {
+                    let p: Point<2, i32> = [x, y].into();
+                    assert_eq!(shape.locate(&p), shape.locate_even_odd(&p), "{p:?} in {shape:?}");
+                }
+            }
+        }
+        // Rays through vertices must not be double counted
+        let w = &shapes[1];
+        assert_eq!(w.locate(&[1, 2].into()), Containment::Inside);
+        assert_eq!(w.locate(&[2, 3].into()), Containment::Outside);
+        assert_eq!(w.locate(&[2, 2].into()), Containment::Boundary);
+        assert_eq!(w.locate(&[-1, 4].into()), Containment::Outside);
+    }
+
+    #[test]
+    fn rules_differ_on_overlapping_loops() {
+        // Goes round the square twice
+        let twice = polygon(&[[0, 0], [2, 0], [2, 2], [0, 2], [0, 0], [2, 0], [2, 2], [0, 2]]);
+        let p: Point<2, i32> = [1, 1].into();
+        assert_eq!(twice.winding_number(&p), 2);
+        assert_eq!(twice.locate(&p), Containment::Inside);
+        assert_eq!(twice.locate_even_odd(&p), Containment::Outside);
+
+        let clockwise = polygon(&[[0, 0], [0, 2], [2, 2], [2, 0]]);
+        assert_eq!(clockwise.winding_number(&p), -1);
+        assert!(clockwise.contains(&p));
+    }
+
+    #[test]
+    fn float_polygon() {
+        let triangle: Polygon<f64> = vec![[0.0, 0.0].into(), [1.0, 0.0].into(), [0.0, 1.0].into()].into();
+        assert_eq!(triangle.locate(&[0.25, 0.25].into()), Containment::Inside);
+        assert_eq!(triangle.locate(&[0.5, 0.5].into()), Containment::Boundary);
+        assert_eq!(triangle.locate(&[0.6, 0.6].into()), Containment::Outside);
+    }
+}
@@ -0,0 +1,409 @@
+use crate::{Containment, Polygon};
+use point::{Aabb, Coordinate, Point};
+use segment::SegmentIntersection;
+use std::cmp::Ordering;
+use std::fmt;
+
+/// A polygon with zero or more holes cut out of it.
+/// ```
+/// use polygon::{Containment, Polygon, PolygonWithHoles};
+/// let shell: Polygon<i32> = vec![[0, 0].into(), [6, 0].into(), [6, 6].into(), [0, 6].into()].into();
+/// let hole: Polygon<i32> = vec![[2, 2].into(), [4, 2].into(), [4, 4].into(), [2, 4].into()].into();
+/// let p = PolygonWithHoles::new(shell, vec![hole]);
+/// assert_eq!(p.validate(), Ok(()));
+/// assert_eq!(p.area(), 32.0);
+/// assert_eq!(p.locate(&[3, 3].into()), Containment::Outside);
+/// assert_eq!(p.locate(&[1, 3].into()), Containment::Inside);
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PolygonWithHoles<N> {
+    pub exterior: Polygon<N>,
+    pub holes: Vec<Polygon<N>>,
+}
+
+/// A set of polygons with disjoint interiors.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MultiPolygon<N> {
+    pub polygons: Vec<PolygonWithHoles<N>>,
+}
+
+/// A ring of a `PolygonWithHoles`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Ring {
+    Exterior,
+    Hole(usize),
+}
+
+/// Why a `PolygonWithHoles` or `MultiPolygon` is invalid. Rings may touch
+/// at isolated points but must not cross or share edges.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValidationError {
+    /// A ring with fewer than three vertices.
+    TooFewVertices(Ring),
+    /// A ring whose edges cross or overlap each other.
+    SelfIntersecting(Ring),
+    /// Two rings whose edges cross or overlap.
+    RingsCross(Ring, Ring),
+    /// A hole that is not inside the exterior ring.
+    HoleOutside(usize),
+    /// The first hole lies inside the second.
+    NestedHoles(usize, usize),
+    /// An error in one polygon of a `MultiPolygon`.
+    InPolygon(usize, Box<ValidationError>),
+    /// Two polygons of a `MultiPolygon` whose boundaries cross or whose
+    /// interiors overlap.
+    PolygonsOverlap(usize, usize),
+}
+
+impl fmt::Display for Ring {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Ring::Exterior => f.write_str("exterior ring"),
+            Ring::Hole(i) => write!(f, "hole {i}"),
+        }
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidationError::TooFewVertices(r) => write!(f, "{r} has fewer than three vertices"),
+            ValidationError::SelfIntersecting(r) => write!(f, "{r} intersects itself"),
+            ValidationError::RingsCross(a, b) => write!(f, "{a} crosses {b}"),
+            ValidationError::HoleOutside(i) => {
+                write!(f, "hole {i} is not inside the exterior ring")
+            }
+            ValidationError::NestedHoles(i, j) => write!(f, "hole {i} is inside hole {j}"),
+            ValidationError::InPolygon(i, e) => write!(f, "polygon {i}: {e}"),
+            ValidationError::PolygonsOverlap(i, j) => write!(f, "polygons {i} and {j} overlap"),
+        }
+    }
+}
+
+impl std::error::Error for ValidationError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ValidationError::InPolygon(_, e) => Some(e.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+fn bounding_box<N: Coordinate>(ring: &Polygon<N>) -> Option<Aabb<2, N>> {
+    Aabb::from_points(ring.vertices.iter().copied())
+}
+
+/// Whether edges of the two rings cross properly or overlap. Touching at
+/// a vertex is allowed.
+fn rings_cross<N: Coordinate>(a: &Polygon<N>, b: &Polygon<N>) -> bool {
+    match (bounding_box(a), bounding_box(b)) {
+        (Some(ba), Some(bb)) if ba.intersects(&bb) => {}
+        _ => return false,
+    }
+    a.edges().any(|e| {
+        b.edges().any(|f| match e.intersection(&f) {
+            SegmentIntersection::None => false,
+            SegmentIntersection::Crossing(c) => c.is_proper(),
+            SegmentIntersection::Overlap(_) => true,
+        })
+    })
+}
+
+pub(crate) fn to_field<N: Coordinate>(ring: &Polygon<N>) -> Polygon<N::Field> {
+    ring.vertices.iter().map(|p| p.to_field()).collect()
+}
+
+/// Sample points of `ring`: its vertices and edge midpoints. When rings do
+/// not cross, every edge lies on one side of another ring apart from touching
+/// points, so these are enough to tell where one ring is relative to another.
+pub(crate) fn samples<N: Coordinate>(ring: &Polygon<N>) -> impl Iterator<Item = Point<2, N::Field>> + '_ {
+    ring.vertices
+        .iter()
+        .map(|p| p.to_field())
+        .chain(ring.edges().map(|e| e.midpoint()))
+}
+
+impl<N: Coordinate> PolygonWithHoles<N> {
+    pub fn new(exterior: Polygon<N>, holes: Vec<Polygon<N>>) -> Self {
+        Self { exterior, holes }
+    }
+
+    fn rings(&self) -> impl Iterator<Item = (Ring, &Polygon<N>)> {
+        std::iter::once((Ring::Exterior, &self.exterior)).chain(
+            self.holes
+                .iter()
+                .enumerate()
+                .map(|(i, h)| (Ring::Hole(i), h)),
+        )
+    }
+
+    /// Checks that every ring is simple, no two rings cross, every hole is
+    /// inside the exterior and no hole is inside another.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        for (ring, polygon) in self.rings() {
+            if polygon.len() < 3 {
+                return Err(ValidationError::TooFewVertices(ring));
+            }
+            if !polygon.is_simple() {
+                return Err(ValidationError::SelfIntersecting(ring));
+            }
+        }
+        let rings: Vec<_> = self.rings().collect();
+        for (i, &(ring_a, a)) in rings.iter().enumerate() {
+            for &(ring_b, b) in &rings[i + 1..] {
+                if rings_cross(a, b) {
+                    return Err(ValidationError::RingsCross(ring_a, ring_b));
+                }
+            }
+        }
+        let exterior = to_field(&self.exterior);
+        for (i, hole) in self.holes.iter().enumerate() {
+            if samples(hole).any(|p| exterior.locate(&p) == Containment::Outside) {
+                return Err(ValidationError::HoleOutside(i));
+            }
+        }
+        for (j, outer) in self.holes.iter().enumerate() {
+            let outer = to_field(outer);
+            for (i, hole) in self.holes.iter().enumerate() {
+                if i != j && samples(hole).any(|p| outer.locate(&p) == Containment::Inside) {
+                    return Err(ValidationError::NestedHoles(i, j));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.validate().is_ok()
+    }
+
+    /// Area of the exterior less the holes, whatever the ring orientations.
+    pub fn area(&self) -> f64 {
+        self.exterior.area() - self.holes.iter().map(|h| h.area()).sum::<f64>()
+    }
+
+    /// Makes the exterior counter-clockwise and the holes clockwise.
+    pub fn normalize(&mut self) {
+        if self.exterior.orientation() == Ordering::Less {
+            self.exterior.reverse();
+        }
+        for hole in &mut self.holes {
+            if hole.orientation() == Ordering::Greater {
+                hole.reverse();
+            }
+        }
+    }
+
+    /// Points inside a hole are outside the polygon; hole boundaries are
+    /// part of the polygon's boundary.
+    pub fn locate(&self, p: &Point<2, N>) -> Containment {
+        match self.exterior.locate(p) {
+            Containment::Inside => {}
+            other => return other,
+        }
+        for hole in &self.holes {
+            match hole.locate(p) {
+                Containment::Inside => return Containment::Outside,
+                Containment::Boundary => return Containment::Boundary,
+                Containment::Outside => {}
+            }
+        }
+        Containment::Inside
+    }
+
+    pub fn contains(&self, p: &Point<2, N>) -> bool {
+        self.locate(p) != Containment::Outside
+    }
+
+    pub(crate) fn to_field(&self) -> PolygonWithHoles<N::Field> {
+        PolygonWithHoles {
+            exterior: to_field(&self.exterior),
+            holes: self.holes.iter().map(to_field).collect(),
+        }
+    }
+}
+
+impl<N> From<Polygon<N>> for PolygonWithHoles<N> {
+    fn from(exterior: Polygon<N>) -> Self {
+        Self {
+            exterior,
+            holes: Vec::new(),
+        }
+    }
+}
+
+impl<N> From<PolygonWithHoles<N>> for MultiPolygon<N> {
+    fn from(polygon: PolygonWithHoles<N>) -> Self {
+        Self { polygons: vec![polygon] }
+    }
+}
+
+impl<N> From<Vec<PolygonWithHoles<N>>> for MultiPolygon<N> {
+    fn from(polygons: Vec<PolygonWithHoles<N>>) -> Self {
+        Self { polygons }
+    }
+}
+
+impl<N> FromIterator<PolygonWithHoles<N>> for MultiPolygon<N> {
+    fn from_iter<I: IntoIterator<Item = PolygonWithHoles<N>>>(iter: I) -> Self {
+        Self {
+            polygons: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<N: Coordinate> MultiPolygon<N> {
+    /// Checks every polygon and that no two polygons cross or overlap. A
+    /// polygon may sit inside a hole of another.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        for (i, p) in self.polygons.iter().enumerate() {
+            p.validate()
+                .map_err(|e| ValidationError::InPolygon(i, Box::new(e)))?;
+        }
+        for (i, a) in self.polygons.iter().enumerate() {
+            for (j, b) in self.polygons.iter().enumerate().skip(i + 1) {
+                let crosses = a
+                    .rings()
+                    .any(|(_, ra)| b.rings().any(|(_, rb)| rings_cross(ra, rb)));
+                let inside = |x: &PolygonWithHoles<N>, y: &PolygonWithHoles<N>| {
+                    let y = y.to_field();
+                    samples(&x.exterior).any(|p| y.locate(&p) == Containment::Inside)
+                };
+                if crosses || inside(a, b) || inside(b, a) {
+                    return Err(ValidationError::PolygonsOverlap(i, j));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.validate().is_ok()
+    }
+
+    pub fn area(&self) -> f64 {
+        self.polygons.iter().map(|p| p.area()).sum()
+    }
+
+    pub fn normalize(&mut self) {
+        for p in &mut self.polygons {
+            p.normalize();
+        }
+    }
+
+    pub fn locate(&self, p: &Point<2, N>) -> Containment {
+        let mut result = Containment::Outside;
+        for polygon in &self.polygons {
+            match polygon.locate(p) {
+                Containment::Inside => return Containment::Inside,
+                Containment::Boundary => result = Containment::Boundary,
+                Containment::Outside => {}
+            }
+        }
+        result
+    }
+
+    pub fn contains(&self, p: &Point<2, N>) -> bool {
+        self.locate(p) != Containment::Outside
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::polygon;
+
+    fn square_with_hole() -> PolygonWithHoles<i32> {
+        PolygonWithHoles::new(
+            polygon(&[[0, 0], [6, 0], [6, 6], [0, 6]]),
+            vec![polygon(&[[2, 2], [4, 2], [4, 4], [2, 4]])],
+        )
+    }
+
+    #[test]
+    fn validation() {
+        let shell = polygon(&[[0, 0], [6, 0], [6, 6], [0, 6]]);
+        assert!(square_with_hole().is_valid());
+        // A hole touching the shell at a vertex is fine
+        let touching =
+            PolygonWithHoles::new(shell.clone(), vec![polygon(&[[0, 0], [3, 1], [1, 3]])]);
+        assert_eq!(touching.validate(), Ok(()));
+
+        let outside =
+            PolygonWithHoles::new(shell.clone(), vec![polygon(&[[7, 7], [8, 7], [8, 8]])]);
+        assert_eq!(outside.validate(), Err(ValidationError::HoleOutside(0)));
+        let crossing =
+            PolygonWithHoles::new(shell.clone(), vec![polygon(&[[4, 4], [8, 4], [8, 5]])]);
+        assert_eq!(
+            crossing.validate(),
+            Err(ValidationError::RingsCross(Ring::Exterior, Ring::Hole(0)))
+        );
+        // Sharing part of an edge counts as crossing
+        let sharing =
+            PolygonWithHoles::new(shell.clone(), vec![polygon(&[[1, 0], [3, 0], [2, 2]])]);
+        assert_eq!(
+            sharing.validate(),
+            Err(ValidationError::RingsCross(Ring::Exterior, Ring::Hole(0)))
+        );
+        let nested = PolygonWithHoles::new(
+            shell.clone(),
+            vec![
+                polygon(&[[1, 1], [5, 1], [5, 5], [1, 5]]),
+                polygon(&[[2, 2], [3, 2], [3, 3]]),
+            ],
+        );
+        assert_eq!(nested.validate(), Err(ValidationError::NestedHoles(1, 0)));
+        let bow_tie =
+            PolygonWithHoles::new(shell, vec![polygon(&[[1, 1], [3, 3], [3, 1], [1, 3]])]);
+        assert_eq!(
+            bow_tie.validate(),
+            Err(ValidationError::SelfIntersecting(Ring::Hole(0)))
+        );
+    }
+
+    #[test]
+    fn area_and_location() {
+        let mut p = square_with_hole();
+        assert_eq!(p.area(), 32.0);
+        p.normalize();
+        assert_eq!(p.exterior.orientation(), Ordering::Greater);
+        assert_eq!(p.holes[0].orientation(), Ordering::Less);
+        assert_eq!(p.area(), 32.0);
+
+        assert_eq!(p.locate(&[1, 1].into()), Containment::Inside);
+        assert_eq!(p.locate(&[3, 3].into()), Containment::Outside);
+        assert_eq!(p.locate(&[4, 3].into()), Containment::Boundary);
+        assert_eq!(p.locate(&[6, 3].into()), Containment::Boundary);
+        assert_eq!(p.locate(&[7, 3].into()), Containment::Outside);
+    }
+
+    #[test]
+    fn multipolygon() {
+        // An island inside the hole of another polygon
+        let island = PolygonWithHoles::from(polygon(&[[3, 3], [4, 3], [3, 4]]));
+        let mut m: MultiPolygon<i32> = vec![square_with_hole(), island].into();
+        assert_eq!(m.validate(), Ok(()));
+        assert_eq!(m.area(), 32.5);
+        assert_eq!(m.locate(&[3, 3].into()), Containment::Boundary);
+        assert!(m.contains(&[1, 1].into()));
+        assert_eq!(m.locate(&[4, 4].into()), Containment::Boundary);
+
+        m.polygons.push(polygon(&[[5, 5], [9, 5], [9, 9]]).into());
+        assert_eq!(m.validate(), Err(ValidationError::PolygonsOverlap(0, 2)));
+        m.polygons[2] = polygon(&[[1, 1], [2, 1], [1, 2]]).into();
+        assert_eq!(m.validate(), Err(ValidationError::PolygonsOverlap(0, 2)));
+        m.polygons[2] = PolygonWithHoles::new(
+            polygon(&[[10, 0], [11, 0], [11, 1]]),
+            vec![polygon(&[[0, 0], [1, 0], [0, 1]])],
+        );
+        assert_eq!(
+            m.validate(),
+            Err(ValidationError::InPolygon(
+                2,
+                Box::new(ValidationError::HoleOutside(0))
+            ))
+        );
+    }
+}
@@ -0,0 +1,220 @@
+use num_traits::Zero;
+use point::{orientation, Coordinate, Point};
+use polyline::Polyline;
+use segment::LineSegment;
+use std::cmp::Ordering;
+
+mod boolean;
+mod clip;
+mod contains;
+mod holes;
+pub use boolean::BooleanOp;
+pub use contains::Containment;
+pub use holes::{MultiPolygon, PolygonWithHoles, Ring, ValidationError};
+
+/// Polygon given by its vertices in order. The ring is closed implicitly,
+/// so the first vertex is not repeated at the end.
+/// ```
+/// use polygon::Polygon;
+/// let square: Polygon<i32> = vec![[0, 0].into(), [2, 0].into(), [2, 2].into(), [0, 2].into()].into();
+/// assert_eq!(square.twice_signed_area(), 8);
+/// assert_eq!(square.perimeter(), 8.0);
+/// assert_eq!(square.centroid(), Some([1.0, 1.0].into()));
+/// assert!(square.is_convex());
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Polygon<N> {
+    pub vertices: Vec<Point<2, N>>,
+}
+
+impl<N> From<Vec<Point<2, N>>> for Polygon<N> {
+    fn from(vertices: Vec<Point<2, N>>) -> Self {
+        Self { vertices }
+    }
+}
+
+impl<N> FromIterator<Point<2, N>> for Polygon<N> {
+    fn from_iter<I: IntoIterator<Item = Point<2, N>>>(iter: I) -> Self {
+        Self { vertices: iter.into_iter().collect() }
+    }
+}
+
+impl<N> Polygon<N> {
+    pub fn len(&self) -> usize {
+        self.vertices.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.vertices.is_empty()
+    }
+
+    /// The same polygon traversed the other way round.
+    pub fn reverse(&mut self) {
+        self.vertices.reverse();
+    }
+}
+
+impl<N: Coordinate> Polygon<N> {
+    /// Edge `i` joins vertex `i` to the next one, wrapping around.
+    pub fn edges(&self) -> impl Iterator<Item = LineSegment<2, N>> + '_ {
+        let n = self.vertices.len();
+        (0..n).map(move |i| LineSegment { start: self.vertices[i], end: self.vertices[(i + 1) % n] })
+    }
+
+    /// Twice the signed area by the shoelace formula, positive for
+    /// counter-clockwise polygons. Exact for integer coordinates.
+    pub fn twice_signed_area(&self) -> N::Wide {
+        self.edges()
+            .map(|e| e.start.widen().cross(&e.end.widen()))
+            .fold(N::Wide::zero(), |acc, c| acc + c)
+    }
+
+    pub fn signed_area(&self) -> f64 {
+        self.twice_signed_area().to_f64() / 2.0
+    }
+
+    pub fn area(&self) -> f64 {
+        self.signed_area().abs()
+    }
+
+    /// `Greater` for counter-clockwise, `Less` for clockwise and `Equal`
+    /// when the signed area is zero, matching `point::orientation`.
+    pub fn orientation(&self) -> Ordering {
+        self.twice_signed_area()
+            .partial_cmp(&N::Wide::zero())
+            .unwrap_or(Ordering::Equal)
+    }
+
+    pub fn is_counter_clockwise(&self) -> bool {
+        self.orientation() == Ordering::Greater
+    }
+
+    pub fn perimeter(&self) -> f64 {
+        self.edges().map(|e| e.length()).sum()
+    }
+
+    /// Centroid of the enclosed area. The sums are of degree three and are
+    /// taken relative to the first vertex. While they are sure to fit, they
+    /// are kept exactly in the wide type's own wide type, `i128` for
+    /// integers, and rounded once at the end: that covers `i32` polygons
+    /// of up to millions of vertices, and `i64` polygons of a thousand
+    /// vertices spanning up to 2^36. Larger ones are summed in `f64`
+    /// instead of overflowing. `None` when the area is zero.
+    pub fn centroid(&self) -> Option<Point<2, f64>> {
+        let first = self.vertices.first()?.widen().widen();
+        let shifted: Vec<_> = self.vertices.iter().map(|v| v.widen().widen() - first).collect();
+        let span = shifted.iter().flat_map(|v| v.coords).map(|c| c.to_f64().abs()).fold(0.0, f64::max);
+        // Each term is at most 4 span^3, and 2^120 leaves room below i128
+        let [cx, cy, twice_area] = if 4.0 * shifted.len() as f64 * span.powi(3) < 2f64.powi(120) {
+            moments(&shifted).map(|m| m.to_f64())
+        } else {
+            let shifted: Vec<Point<2, f64>> = shifted.iter().map(|v| v.coords.map(|c| c.to_f64()).into()).collect();
+            moments(&shifted)
+        };
+        if twice_area == 0.0 {
+            return None;
+        }
+        let denominator = 3.0 * twice_area;
+        Some([first.x().to_f64() + cx / denominator, first.y().to_f64() + cy / denominator].into())
+    }
+
+    /// Whether no two edges meet except neighbouring edges at their shared
+    /// vertex. Needs at least three vertices.
+    pub fn is_simple(&self) -> bool {
+        if self.vertices.len() < 3 {
+            return false;
+        }
+        let ring: Polyline<N> = self.vertices.iter().chain(self.vertices.first()).copied().collect();
+        ring.is_simple()
+    }
+
+    /// Whether the polygon is simple with every turn in the same direction.
+    /// Collinear vertices are allowed.
+    pub fn is_convex(&self) -> bool {
+        let n = self.vertices.len();
+        let mut turn = Ordering::Equal;
+        for i in 0..n {
+            let o = orientation(&self.vertices[i], &self.vertices[(i + 1) % n], &self.vertices[(i + 2) % n]);
+            if o.is_ne() {
+                if turn.is_ne() && o != turn {
+                    return false;
+                }
+                turn = o;
+            }
+        }
+        // A pentagram turns the same way at every vertex but is not simple
+        turn.is_ne() && self.is_simple()
+    }
+}
+
+/// The sums of `(a + b) * (a × b)` over the edges `a, b` of `ring`, by
+/// axis, and of `a × b`, which is twice the signed area.
+fn moments<T: Coordinate>(ring: &[Point<2, T>]) -> [T; 3] {
+    let n = ring.len();
+    (0..n).fold([T::zero(); 3], |[cx, cy, area], i| {
+        let (a, b) = (ring[i], ring[(i + 1) % n]);
+        let cross = a.cross(&b);
+        [cx + (a.x() + b.x()) * cross, cy + (a.y() + b.y()) * cross, area + cross]
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    pub(crate) fn polygon(points: &[[i32; 2]]) -> Polygon<i32> {
+        points.iter().map(|&p| Point::from(p)).collect()
+    }
+
+    #[test]
+    fn area_and_orientation() {
+        let mut l_shape = polygon(&[[0, 0], [4, 0], [4, 1], [1, 1], [1, 3], [0, 3]]);
+        assert_eq!(l_shape.twice_signed_area(), 12);
+        assert_eq!(l_shape.orientation(), Ordering::Greater);
+        l_shape.reverse();
+        assert_eq!(l_shape.signed_area(), -6.0);
+        assert_eq!(l_shape.area(), 6.0);
+        assert!(!l_shape.is_counter_clockwise());
+        assert_eq!(polygon(&[[0, 0], [1, 1], [2, 2]]).orientation(), Ordering::Equal);
+    }
+
+    #[test]
+    fn centroid() {
+        let triangle = polygon(&[[0, 0], [6, 0], [0, 3]]);
+        assert_eq!(triangle.centroid(), Some([2.0, 1.0].into()));
+        // Starting from a different vertex
+        let l_shape = polygon(&[[0, 3], [0, 0], [4, 0], [4, 1], [1, 1], [1, 3]]);
+        let c = l_shape.centroid().unwrap();
+        assert!((c.x() - 1.5).abs() < 1e-12 && (c.y() - 1.0).abs() < 1e-12);
+        assert_eq!(polygon(&[[0, 0], [1, 1]]).centroid(), None);
+        // Degree-three sums that would overflow i64
+        let big = polygon(&[[0, 0], [3_000_000, 0], [3_000_000, 3_000_000], [0, 3_000_000]]);
+        assert_eq!(big.centroid(), Some([1_500_000.0, 1_500_000.0].into()));
+        let m = 1 << 29;
+        let far = polygon(&[[-m, -m], [m, -m], [m, m - 2], [-m, m - 2]]);
+        assert_eq!(far.centroid(), Some([0.0, -1.0].into()));
+        // Past the exact range for i64 the sums are taken in f64
+        let s: i64 = 1 << 45;
+        let huge: Polygon<i64> = [[-s, 0], [s, 0], [s, 2 * s], [-s, 2 * s]].into_iter().map(Point::from).collect();
+        assert_eq!(huge.centroid(), Some([0.0, s as f64].into()));
+        let offset: Polygon<i64> = [[s, s], [s + 6, s], [s, s + 3]].into_iter().map(Point::from).collect();
+        assert_eq!(offset.centroid(), Some([s as f64 + 2.0, s as f64 + 1.0].into()));
+    }
+
+    #[test]
+    fn convexity_and_simplicity() {
+        assert!(polygon(&[[0, 0], [4, 0], [4, 4], [2, 4], [0, 4]]).is_convex());
+        assert!(polygon(&[[0, 0], [0, 4], [4, 4], [4, 0]]).is_convex());
+        let l_shape = polygon(&[[0, 0], [4, 0], [4, 1], [1, 1], [1, 3], [0, 3]]);
+        assert!(l_shape.is_simple());
+        assert!(!l_shape.is_convex());
+
+        let pentagram = polygon(&[[0, 10], [6, -8], [-10, 3], [10, 3], [-6, -8]]);
+        assert!(!pentagram.is_simple());
+        assert!(!pentagram.is_convex());
+        let bow_tie = polygon(&[[0, 0], [2, 2], [2, 0], [0, 2]]);
+        assert!(!bow_tie.is_simple());
+        assert!(!polygon(&[[0, 0], [1, 1]]).is_simple());
+    }
+}
@@ -0,0 +1,212 @@
+use point::{Coordinate, Point};
+use std::cmp::Ordering;
+use segment::{LineSegment, SegmentIntersection};
+
+mod simplify;
+
+/// A chain of points joined by straight segments, such as a GPS trace.
+/// ```
+/// use polyline::Polyline;
+/// let trace: Polyline<f64> = vec![[0.0, 0.0].into(), [3.0, 4.0].into(), [3.0, 10.0].into()].into();
+/// assert_eq!(trace.length(), 11.0);
+/// assert_eq!(trace.segments().count(), 2);
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Polyline<N> {
+    pub points: Vec<Point<2, N>>,
+}
+
+impl<N> From<Vec<Point<2, N>>> for Polyline<N> {
+    fn from(points: Vec<Point<2, N>>) -> Self {
+        Self { points }
+    }
+}
+
+impl<N> FromIterator<Point<2, N>> for Polyline<N> {
+    fn from_iter<I: IntoIterator<Item = Point<2, N>>>(iter: I) -> Self {
+        Self { points: iter.into_iter().collect() }
+    }
+}
+
+impl<N> Polyline<N> {
+    pub fn new() -> Self {
+        Self { points: Vec::new() }
+    }
+
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    pub fn push(&mut self, p: Point<2, N>) {
+        self.points.push(p);
+    }
+}
+
+impl<N: Coordinate> Polyline<N> {
+    /// Segment `i` joins points `i` and `i + 1`.
+    pub fn segments(&self) -> impl Iterator<Item = LineSegment<2, N>> + '_ {
+        self.points.windows(2).map(|w| LineSegment { start: w[0], end: w[1] })
+    }
+
+    /// Whether the last point repeats the first.
+    pub fn is_closed(&self) -> bool {
+        self.points.len() > 2 && self.points.first() == self.points.last()
+    }
+
+    pub fn length(&self) -> f64 {
+        self.segments().map(|s| s.length()).sum()
+    }
+
+    /// Pairs `(i, j)` with `i < j` of segments that meet anywhere other
+    /// than the vertex joining consecutive segments. The first and last
+    /// segments of a closed polyline count as consecutive. Repeated points
+    /// make zero-length segments, which are skipped, so the segments on
+    /// either side of them count as consecutive too. Exact for
+    /// integer coordinates.
+    /// ```
+    /// use polyline::Polyline;
+    /// // A bow tie crosses itself in the middle
+    /// let p: Polyline<i32> = vec![[0, 0].into(), [2, 2].into(), [2, 0].into(), [0, 2].into()].into();
+    /// assert_eq!(p.self_intersections(), vec![(0, 2)]);
+    /// ```
+    pub fn self_intersections(&self) -> Vec<(usize, usize)> {
+        // Original indices of the segments with nonzero length
+        let (index, segments): (Vec<usize>, Vec<_>) =
+            self.segments().enumerate().filter(|(_, s)| s.start != s.end).unzip();
+        let last = segments.len().saturating_sub(1);
+        let closed = self.is_closed();
+        let x_range = |s: &LineSegment<2, N>| {
+            if s.start.x() < s.end.x() { (s.start.x(), s.end.x()) } else { (s.end.x(), s.start.x()) }
+        };
+
+        // Sweep along x so only segments with overlapping x ranges are tested
+        let mut order: Vec<usize> = (0..segments.len()).collect();
+        order.sort_by(|&a, &b| x_range(&segments[a]).0.partial_cmp(&x_range(&segments[b]).0).unwrap_or(Ordering::Equal));
+        let mut active: Vec<usize> = Vec::new();
+        let mut found = Vec::new();
+        for &j in &order {
+            let (min_x, _) = x_range(&segments[j]);
+            active.retain(|&i| x_range(&segments[i]).1 >= min_x);
+            for &i in &active {
+                let (i, j) = if i < j { (i, j) } else { (j, i) };
+                let shared = if j == i + 1 {
+                    Some(segments[i].end)
+                } else if closed && i == 0 && j == last {
+                    Some(segments[i].start)
+                } else {
+                    None
+                };
+                let meets = match shared {
+                    None => segments[i].intersects_bool(&segments[j]),
+                    Some(vertex) => match segments[i].intersection(&segments[j]) {
+                        SegmentIntersection::None => false,
+                        SegmentIntersection::Crossing(c) => c.point != vertex.to_field(),
+                        SegmentIntersection::Overlap(_) => true,
+                    },
+                };
+                if meets {
+                    found.push((index[i], index[j]));
+                }
+            }
+            active.push(j);
+        }
+        found.sort_unstable();
+        found
+    }
+
+    /// No two segments meet except consecutive ones at their shared vertex.
+    pub fn is_simple(&self) -> bool {
+        self.self_intersections().is_empty()
+    }
+
+    /// Points spaced `spacing` apart along the polyline, measured by arc
+    /// length, starting at the first point. The last point is always kept.
+    /// Panics unless `spacing` is positive.
+    /// ```
+    /// use polyline::Polyline;
+    /// let p: Polyline<i32> = vec![[0, 0].into(), [4, 0].into(), [4, 2].into()].into();
+    /// let r = p.resample(2.5);
+    /// assert_eq!(r.points, vec![[0.0, 0.0].into(), [2.5, 0.0].into(), [4.0, 1.0].into(), [4.0, 2.0].into()]);
+    /// ```
+    pub fn resample(&self, spacing: f64) -> Polyline<f64> {
+        assert!(spacing > 0.0, "resample spacing must be positive");
+        let mut out = Polyline::new();
+        let Some(first) = self.points.first() else {
+            return out;
+        };
+        out.push(first.to_f64());
+        // Arc length from the start of the current segment to the next sample
+        let mut next = spacing;
+        for s in self.segments() {
+            let (a, b) = (s.start.to_f64(), s.end.to_f64());
+            let len = s.length();
+            while next < len {
+                let t = next / len;
+                out.push(Point { coords: std::array::from_fn(|i| a.coords[i] + (b.coords[i] - a.coords[i]) * t) });
+                next += spacing;
+            }
+            next -= len;
+        }
+        let last = self.points[self.points.len() - 1].to_f64();
+        if self.points.len() > 1 && out.points.last() != Some(&last) {
+            out.push(last);
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn polyline(points: &[[i32; 2]]) -> Polyline<i32> {
+        points.iter().map(|&p| Point::from(p)).collect()
+    }
+
+    #[test]
+    fn simple_chains() {
+        assert!(polyline(&[]).is_simple());
+        assert!(polyline(&[[0, 0], [1, 0], [1, 1], [0, 1]]).is_simple());
+        // A closed square only meets itself at the shared first vertex
+        let square = polyline(&[[0, 0], [1, 0], [1, 1], [0, 1], [0, 0]]);
+        assert!(square.is_closed());
+        assert!(square.is_simple());
+        assert_eq!(square.length(), 4.0);
+    }
+
+    #[test]
+    fn self_intersections() {
+        // Doubling back over the previous segment
+        assert_eq!(polyline(&[[0, 0], [4, 0], [2, 0]]).self_intersections(), vec![(0, 1)]);
+        // Touching an earlier vertex
+        assert_eq!(polyline(&[[0, 0], [4, 0], [4, 4], [2, 0]]).self_intersections(), vec![(0, 2)]);
+        // A spiral crossing two earlier segments
+        let p = polyline(&[[0, 0], [6, 0], [6, 6], [2, 6], [2, 2], [8, 2]]);
+        assert_eq!(p.self_intersections(), vec![(1, 4)]);
+        let p = polyline(&[[0, 0], [6, 0], [6, 6], [2, 6], [2, -2]]);
+        assert_eq!(p.self_intersections(), vec![(0, 3)]);
+        // Repeated points are not crossings, and do not hide real ones
+        let p = polyline(&[[0, 0], [4, 0], [4, 0], [4, 4], [4, 4], [0, 0]]);
+        assert!(p.self_intersections().is_empty());
+        let p = polyline(&[[0, 0], [0, 0], [4, 0], [4, 4], [4, 4], [2, 0]]);
+        assert_eq!(p.self_intersections(), vec![(1, 4)]);
+        // NaN gives some answer rather than a panic
+        let p: Polyline<f64> = vec![[0.0, 0.0].into(), [f64::NAN, 1.0].into(), [2.0, 0.0].into()].into();
+        let _ = p.self_intersections();
+    }
+
+    #[test]
+    fn resample() {
+        let p = polyline(&[[0, 0], [3, 0], [3, 3]]);
+        let r = p.resample(1.0);
+        assert_eq!(r.len(), 7);
+        assert_eq!(r.points[3], [3.0, 0.0].into());
+        assert_eq!(r.points[6], [3.0, 3.0].into());
+        assert_eq!(polyline(&[[1, 1]]).resample(1.0).points, vec![[1.0, 1.0].into()]);
+    }
+}
@@ -0,0 +1,165 @@
+use crate::Polyline;
+use point::Coordinate;
+use segment::LineSegment;
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+impl<N: Coordinate> Polyline<N> {
+    /// Douglas–Peucker simplification. Keeps the endpoints and every point
+    /// needed so that no dropped point is further than `tolerance` from
+    /// the simplified polyline.
+    /// ```
+    /// use polyline::Polyline;
+    /// let p: Polyline<i32> = vec![[0, 0].into(), [1, 1].into(), [2, 0].into(), [3, 5].into(), [4, 0].into()].into();
+    /// assert_eq!(p.simplify_douglas_peucker(2.0).points, vec![[0, 0].into(), [3, 5].into(), [4, 0].into()]);
+    /// ```
+    pub fn simplify_douglas_peucker(&self, tolerance: f64) -> Self {
+        let n = self.points.len();
+        if n < 3 {
+            return self.clone();
+        }
+        let mut keep = vec![false; n];
+        keep[0] = true;
+        keep[n - 1] = true;
+        // Explicit stack so long traces cannot overflow the call stack
+        let mut stack = vec![(0, n - 1)];
+        while let Some((first, last)) = stack.pop() {
+            let chord = LineSegment { start: self.points[first], end: self.points[last] };
+            let farthest = (first + 1..last)
+                .map(|i| (i, chord.square_distance_to_point(&self.points[i]).to_f64()))
+                .max_by(|a, b| a.1.total_cmp(&b.1));
+            if let Some((i, d)) = farthest {
+                if d > tolerance * tolerance {
+                    keep[i] = true;
+                    stack.push((first, i));
+                    stack.push((i, last));
+                }
+            }
+        }
+        self.points
+            .iter()
+            .zip(keep)
+            .filter_map(|(&p, k)| k.then_some(p))
+            .collect()
+    }
+
+    /// Visvalingam–Whyatt simplification. Repeatedly drops the point
+    /// forming the smallest triangle with its neighbours until every
+    /// remaining interior point's triangle has area at least `min_area`.
+    /// The endpoints are always kept.
+    /// ```
+    /// use polyline::Polyline;
+    /// let p: Polyline<i32> = vec![[0, 0].into(), [1, 1].into(), [2, 0].into(), [3, 5].into(), [4, 0].into()].into();
+    /// assert_eq!(p.simplify_visvalingam_whyatt(6.0).points, vec![[0, 0].into(), [3, 5].into(), [4, 0].into()]);
+    /// ```
+    pub fn simplify_visvalingam_whyatt(&self, min_area: f64) -> Self {
+        let n = self.points.len();
+        if n < 3 {
+            return self.clone();
+        }
+        let area = |a: usize, b: usize, c: usize| {
+            let (a, b, c) = (self.points[a].widen(), self.points[b].widen(), self.points[c].widen());
+            (b - a).cross(&(c - a)).to_f64().abs() / 2.0
+        };
+
+        let mut prev: Vec<usize> = (0..n).map(|i| i.wrapping_sub(1)).collect();
+        let mut next: Vec<usize> = (1..=n).collect();
+        let mut areas: Vec<f64> = (0..n)
+            .map(|i| if i == 0 || i == n - 1 { f64::INFINITY } else { area(i - 1, i, i + 1) })
+            .collect();
+        let mut heap: BinaryHeap<Candidate> = (1..n - 1).map(|i| Candidate { area: areas[i], index: i }).collect();
+        let mut removed = vec![false; n];
+
+        while let Some(Candidate { area: a, index: i }) = heap.pop() {
+            if removed[i] || a != areas[i] {
+                continue;
+            }
+            if a >= min_area {
+                break;
+            }
+            removed[i] = true;
+            let (p, q) = (prev[i], next[i]);
+            next[p] = q;
+            prev[q] = p;
+            // A neighbour's area never drops below the one just removed, so
+            // points are eliminated in order of their effective area
+            for j in [p, q] {
+                if j != 0 && j != n - 1 {
+                    areas[j] = area(prev[j], j, next[j]).max(a);
+                    heap.push(Candidate { area: areas[j], index: j });
+                }
+            }
+        }
+        self.points
+            .iter()
+            .zip(removed)
+            .filter_map(|(&p, r)| (!r).then_some(p))
+            .collect()
+    }
+}
+
+/// Min-heap entry ordered by area.
+#[derive(Debug, PartialEq)]
+struct Candidate {
+    area: f64,
+    index: usize,
+}
+
+impl Eq for Candidate {}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Candidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.area.total_cmp(&self.area).then(other.index.cmp(&self.index))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use point::Point;
+
+    fn zigzag(n: i32, amplitude: i32) -> Polyline<i32> {
+        (0..n).map(|i| Point::from([i * 10, if i % 2 == 0 { 0 } else { amplitude }])).collect()
+    }
+
+    #[test]
+    fn douglas_peucker() {
+        let p = zigzag(9, 1);
+        let s = p.simplify_douglas_peucker(2.0);
+        assert_eq!(s.points, vec![[0, 0].into(), [80, 0].into()]);
+        assert_eq!(p.simplify_douglas_peucker(0.5), p);
+
+        // Every dropped point stays within tolerance of the result
+        let wave: Polyline<f64> = (0..200).map(|i| Point::from([i as f64, (i as f64 / 10.0).sin() * 20.0])).collect();
+        let s = wave.simplify_douglas_peucker(0.5);
+        assert!(s.len() < wave.len() / 4);
+        for p in &wave.points {
+            let d = s.segments().map(|seg| seg.distance_to_point(p)).fold(f64::INFINITY, f64::min);
+            assert!(d <= 0.5 + 1e-9);
+        }
+    }
+
+    #[test]
+    fn visvalingam_whyatt() {
+        let p = zigzag(9, 1);
+        assert_eq!(p.simplify_visvalingam_whyatt(50.0).points, vec![[0, 0].into(), [80, 0].into()]);
+        assert_eq!(p.simplify_visvalingam_whyatt(5.0), p);
+
+        // Collinear points have zero area and go first
+        let line: Polyline<i32> = (0..5).map(|i| Point::from([i, 2 * i])).collect();
+        assert_eq!(line.simplify_visvalingam_whyatt(f64::MIN_POSITIVE).points, vec![[0, 0].into(), [4, 8].into()]);
+    }
+
+    #[test]
+    fn short_inputs() {
+        let p: Polyline<i32> = vec![[0, 0].into(), [5, 5].into()].into();
+        assert_eq!(p.simplify_douglas_peucker(100.0), p);
+        assert_eq!(p.simplify_visvalingam_whyatt(100.0), p);
+    }
+}
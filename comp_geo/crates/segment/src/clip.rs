@@ -0,0 +1,175 @@
+use crate::LineSegment;
+use num_traits::{One, Zero};
+use point::{Aabb, Coordinate, Point};
+
+// Cohen–Sutherland outcode bits
+const LEFT: u8 = 1;
+const RIGHT: u8 = 2;
+const BOTTOM: u8 = 4;
+const TOP: u8 = 8;
+
+fn outcode<F: Coordinate>(p: &Point<2, F>, window: &Aabb<2, F>) -> u8 {
+    let mut code = 0;
+    if p.x() < window.min.x() {
+        code |= LEFT;
+    } else if p.x() > window.max.x() {
+        code |= RIGHT;
+    }
+    if p.y() < window.min.y() {
+        code |= BOTTOM;
+    } else if p.y() > window.max.y() {
+        code |= TOP;
+    }
+    code
+}
+
+fn window_to_field<const D: usize, N: Coordinate>(window: &Aabb<D, N>) -> Aabb<D, N::Field> {
+    Aabb { min: window.min.to_field(), max: window.max.to_field() }
+}
+
+impl<N: Coordinate> LineSegment<2, N> {
+    /// The part of the segment inside the closed box `window`, by
+    /// Cohen–Sutherland clipping. Exact for integer coordinates.
+    /// ```
+    /// use point::{Aabb, Rational128};
+    /// use segment::LineSegment;
+    /// let window = Aabb::new([0, 0].into(), [4, 4].into());
+    /// let seg = LineSegment { start: [-2, 1].into(), end: [6, 5].into() };
+    /// let clipped = seg.clip_cohen_sutherland(&window).unwrap();
+    /// assert_eq!(clipped.start, [0, 2].map(Rational128::from).into());
+    /// assert_eq!(clipped.end, [4, 4].map(Rational128::from).into());
+    /// ```
+    pub fn clip_cohen_sutherland(&self, window: &Aabb<2, N>) -> Option<LineSegment<2, N::Field>> {
+        let window = window_to_field(window);
+        let (mut a, mut b) = (self.start.to_field(), self.end.to_field());
+        let (mut code_a, mut code_b) = (outcode(&a, &window), outcode(&b, &window));
+        loop {
+            if code_a | code_b == 0 {
+                return Some(LineSegment { start: a, end: b });
+            }
+            if code_a & code_b != 0 {
+                return None;
+            }
+            // Move an outside endpoint onto the edge it is beyond
+            let code = if code_a != 0 { code_a } else { code_b };
+            let d = b - a;
+            let p: Point<2, N::Field> = if code & (LEFT | RIGHT) != 0 {
+                let x = if code & LEFT != 0 { window.min.x() } else { window.max.x() };
+                [x, a.y() + d.y() * (x - a.x()) / d.x()].into()
+            } else {
+                let y = if code & BOTTOM != 0 { window.min.y() } else { window.max.y() };
+                [a.x() + d.x() * (y - a.y()) / d.y(), y].into()
+            };
+            if code == code_a {
+                a = p;
+                code_a = outcode(&a, &window);
+            } else {
+                b = p;
+                code_b = outcode(&b, &window);
+            }
+        }
+    }
+}
+
+impl<const D: usize, N: Coordinate> LineSegment<D, N> {
+    /// The parameters `(t0, t1)` of the part of the segment inside the
+    /// closed box `window`, by Liang–Barsky clipping, or `None` when the
+    /// segment misses the box.
+    pub fn clip_parameters(&self, window: &Aabb<D, N>) -> Option<(N::Field, N::Field)> {
+        let window = window_to_field(window);
+        let a = self.start.to_field();
+        let d = self.end.to_field() - a;
+        let (mut t0, mut t1) = (N::Field::zero(), N::Field::one());
+        for i in 0..D {
+            // p * t <= q for the lower and upper face of each slab
+            let lower = (N::Field::zero() - d.coords[i], a.coords[i] - window.min.coords[i]);
+            let upper = (d.coords[i], window.max.coords[i] - a.coords[i]);
+            for (p, q) in [lower, upper] {
+                if p.is_zero() {
+                    if q < N::Field::zero() {
+                        return None;
+                    }
+                } else if p < N::Field::zero() {
+                    let r = q / p;
+                    if r > t0 {
+                        t0 = r;
+                    }
+                } else {
+                    let r = q / p;
+                    if r < t1 {
+                        t1 = r;
+                    }
+                }
+            }
+        }
+        (t0 <= t1).then_some((t0, t1))
+    }
+
+    /// The part of the segment inside the closed box `window`, by
+    /// Liang–Barsky clipping. Works in any dimension.
+    /// ```
+    /// use point::Aabb;
+    /// use segment::LineSegment;
+    /// let window = Aabb::new([0.0, 0.0, 0.0].into(), [1.0, 1.0, 1.0].into());
+    /// let seg = LineSegment { start: [-1.0, 0.5, 0.5].into(), end: [3.0, 0.5, 0.5].into() };
+    /// let clipped = seg.clip_liang_barsky(&window).unwrap();
+    /// assert_eq!((clipped.start, clipped.end), ([0.0, 0.5, 0.5].into(), [1.0, 0.5, 0.5].into()));
+    /// ```
+    pub fn clip_liang_barsky(&self, window: &Aabb<D, N>) -> Option<LineSegment<D, N::Field>> {
+        let (t0, t1) = self.clip_parameters(window)?;
+        Some(LineSegment { start: self.point_at(t0), end: self.point_at(t1) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use point::Rational128;
+
+    fn r(n: i128) -> Rational128 {
+        Rational128::from(n)
+    }
+
+    fn field(seg: LineSegment<2, i32>) -> LineSegment<2, Rational128> {
+        LineSegment { start: seg.start.to_field(), end: seg.end.to_field() }
+    }
+
+    #[test]
+    fn both_clippers_agree() {
+        let window = Aabb::new([0, 0].into(), [6, 4].into());
+        let cases: [([i32; 2], [i32; 2]); 7] = [
+            ([1, 1], [5, 3]),
+            ([-3, 2], [9, 2]),
+            ([3, -2], [9, 10]),
+            ([-1, -1], [-1, 5]),
+            ([-2, 3], [2, 7]),
+            ([6, 4], [8, 8]),
+            ([-3, -3], [9, 9]),
+        ];
+        for (a, b) in cases {
+            let seg = LineSegment { start: a.into(), end: b.into() };
+            assert_eq!(seg.clip_cohen_sutherland(&window), seg.clip_liang_barsky(&window), "{seg:?}");
+        }
+    }
+
+    #[test]
+    fn clipping() {
+        let window = Aabb::new([0, 0].into(), [6, 4].into());
+        let inside = LineSegment { start: [1, 1].into(), end: [5, 3].into() };
+        assert_eq!(inside.clip_liang_barsky(&window), Some(field(inside)));
+
+        let across = LineSegment { start: [-3, 2].into(), end: [9, 2].into() };
+        assert_eq!(across.clip_parameters(&window), Some((Rational128::new(1, 4), Rational128::new(3, 4))));
+        assert_eq!(across.clip_cohen_sutherland(&window), Some(field(LineSegment { start: [0, 2].into(), end: [6, 2].into() })));
+
+        // Passes by the corner without entering the window
+        let miss = LineSegment { start: [-2, 3].into(), end: [2, 7].into() };
+        assert_eq!(miss.clip_cohen_sutherland(&window), None);
+        assert_eq!(miss.clip_liang_barsky(&window), None);
+
+        // Only the corner is inside
+        let corner = LineSegment { start: [6, 4].into(), end: [8, 8].into() };
+        let dot = LineSegment { start: Point::from([r(6), r(4)]), end: Point::from([r(6), r(4)]) };
+        assert_eq!(corner.clip_liang_barsky(&window), Some(dot));
+    }
+}
@@ -0,0 +1,182 @@
+use crate::LineSegment;
+use num_traits::{One, Zero};
+use point::{Coordinate, Point};
+
+fn clamp_unit<F: Coordinate>(t: F) -> F {
+    if t < F::zero() {
+        F::zero()
+    } else if t > F::one() {
+        F::one()
+    } else {
+        t
+    }
+}
+
+fn along<const D: usize, F: Coordinate>(start: &Point<D, F>, direction: &Point<D, F>, t: F) -> Point<D, F> {
+    Point { coords: std::array::from_fn(|i| start.coords[i] + direction.coords[i] * t) }
+}
+
+/// Distance and closest point queries. All results are in `N::Field`, so
+/// they are exact for integer segments. A zero-length segment behaves as
+/// the single point it covers.
+/// ```
+/// use point::Rational128;
+/// use segment::LineSegment;
+/// let seg = LineSegment { start: [0, 0].into(), end: [4, 2].into() };
+/// assert_eq!(seg.projection_parameter(&[1, 3].into()), Rational128::new(1, 2));
+/// assert_eq!(seg.closest_point(&[1, 3].into()), [2, 1].map(Rational128::from).into());
+/// assert_eq!(seg.square_distance_to_point(&[1, 3].into()), Rational128::from(5));
+/// ```
+impl<const D: usize, N: Coordinate> LineSegment<D, N> {
+    /// The `t` with `start + t * (end - start)` closest to `p` on the
+    /// infinite line, not clamped to the segment. Zero for a zero-length
+    /// segment.
+    pub fn projection_parameter(&self, p: &Point<D, N>) -> N::Field {
+        let a = self.start.to_field();
+        let d = self.end.to_field() - a;
+        let len = d.dot(&d);
+        if len.is_zero() {
+            return N::Field::zero();
+        }
+        (p.to_field() - a).dot(&d) / len
+    }
+
+    /// The point of the segment closest to `p`.
+    pub fn closest_point(&self, p: &Point<D, N>) -> Point<D, N::Field> {
+        let a = self.start.to_field();
+        let d = self.end.to_field() - a;
+        along(&a, &d, clamp_unit(self.projection_parameter(p)))
+    }
+
+    pub fn square_distance_to_point(&self, p: &Point<D, N>) -> N::Field {
+        let c = self.closest_point(p);
+        let v = p.to_field() - c;
+        v.dot(&v)
+    }
+
+    pub fn distance_to_point(&self, p: &Point<D, N>) -> f64 {
+        self.square_distance_to_point(p).to_f64().sqrt()
+    }
+
+    /// A closest pair of points, the first on `self` and the second on
+    /// `other`. The two are equal when the segments intersect. When
+    /// several pairs are closest, as for overlapping parallel segments,
+    /// any one of them is returned.
+    /// ```
+    /// use point::Rational128;
+    /// use segment::LineSegment;
+    /// let a: LineSegment<3, i32> = LineSegment { start: [0, 0, 0].into(), end: [2, 0, 0].into() };
+    /// let b = LineSegment { start: [1, -1, 1].into(), end: [1, 1, 1].into() };
+    /// let (p, q) = a.closest_points(&b);
+    /// assert_eq!(p, [1, 0, 0].map(Rational128::from).into());
+    /// assert_eq!(q, [1, 0, 1].map(Rational128::from).into());
+    /// ```
+    pub fn closest_points(&self, other: &Self) -> (Point<D, N::Field>, Point<D, N::Field>) {
+        // Ericson, Real-Time Collision Detection, section 5.1.9
+        let zero = N::Field::zero();
+        let one = N::Field::one();
+        let p1 = self.start.to_field();
+        let p2 = other.start.to_field();
+        let d1 = self.end.to_field() - p1;
+        let d2 = other.end.to_field() - p2;
+        let r = p1 - p2;
+        let a = d1.dot(&d1);
+        let e = d2.dot(&d2);
+        let f = d2.dot(&r);
+
+        let (s, t) = if a.is_zero() && e.is_zero() {
+            (zero, zero)
+        } else if a.is_zero() {
+            (zero, clamp_unit(f / e))
+        } else {
+            let c = d1.dot(&r);
+            if e.is_zero() {
+                (clamp_unit(zero - c / a), zero)
+            } else {
+                let b = d1.dot(&d2);
+                let denom = a * e - b * b;
+                // Parallel segments have no unique closest pair, start from s = 0
+                let s = if denom.is_zero() { zero } else { clamp_unit((b * f - c * e) / denom) };
+                let t = (b * s + f) / e;
+                if t < zero {
+                    (clamp_unit(zero - c / a), zero)
+                } else if t > one {
+                    (clamp_unit((b - c) / a), one)
+                } else {
+                    (s, t)
+                }
+            }
+        };
+        (along(&p1, &d1, s), along(&p2, &d2, t))
+    }
+
+    /// Zero when the segments intersect.
+    pub fn square_distance(&self, other: &Self) -> N::Field {
+        let (p, q) = self.closest_points(other);
+        let v = p - q;
+        v.dot(&v)
+    }
+
+    pub fn distance(&self, other: &Self) -> f64 {
+        self.square_distance(other).to_f64().sqrt()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use point::Rational128;
+
+    fn r(n: i128) -> Rational128 {
+        Rational128::from(n)
+    }
+
+    #[test]
+    fn point_queries() {
+        let seg = LineSegment { start: [0, 0].into(), end: [4, 0].into() };
+        assert_eq!(seg.projection_parameter(&[-2, 1].into()), Rational128::new(-1, 2));
+        assert_eq!(seg.closest_point(&[-2, 1].into()), Point { coords: [r(0), r(0)] });
+        assert_eq!(seg.square_distance_to_point(&[-2, 1].into()), r(5));
+        assert_eq!(seg.closest_point(&[7, -4].into()), Point { coords: [r(4), r(0)] });
+        assert_eq!(seg.distance_to_point(&[7, -4].into()), 5.0);
+        assert_eq!(seg.square_distance_to_point(&[3, 0].into()), r(0));
+    }
+
+    #[test]
+    fn zero_length() {
+        let dot = LineSegment { start: [1, 1].into(), end: [1, 1].into() };
+        assert_eq!(dot.projection_parameter(&[4, 5].into()), r(0));
+        assert_eq!(dot.square_distance_to_point(&[4, 5].into()), r(25));
+
+        let seg = LineSegment { start: [0, 0].into(), end: [0, 4].into() };
+        assert_eq!(dot.square_distance(&seg), r(1));
+        assert_eq!(seg.square_distance(&dot), r(1));
+        assert_eq!(dot.square_distance(&dot), r(0));
+    }
+
+    #[test]
+    fn segment_pairs() {
+        let a = LineSegment { start: [0, 0].into(), end: [4, 4].into() };
+        let crossing = LineSegment { start: [0, 4].into(), end: [4, 0].into() };
+        assert_eq!(a.square_distance(&crossing), r(0));
+        assert_eq!(a.closest_points(&crossing).0, Point { coords: [r(2), r(2)] });
+
+        let apart = LineSegment { start: [5, 0].into(), end: [7, -2].into() };
+        assert_eq!(a.square_distance(&apart), Rational128::new(25, 2));
+        assert_eq!(apart.square_distance(&a), Rational128::new(25, 2));
+
+        let parallel = LineSegment { start: [3, 0].into(), end: [9, 6].into() };
+        assert_eq!(a.square_distance(&parallel), Rational128::new(9, 2));
+
+        let collinear = LineSegment { start: [6, 6].into(), end: [9, 9].into() };
+        assert_eq!(a.square_distance(&collinear), r(8));
+    }
+
+    #[test]
+    fn float_segments() {
+        let a: LineSegment<3, f64> = LineSegment { start: [0.0, 0.0, 0.0].into(), end: [1.0, 0.0, 0.0].into() };
+        let b = LineSegment { start: [0.5, 1.0, 2.0].into(), end: [0.5, 1.0, 3.0].into() };
+        assert_eq!(a.distance(&b), 5.0f64.sqrt());
+        assert_eq!(a.closest_points(&b), ([0.5, 0.0, 0.0].into(), [0.5, 1.0, 2.0].into()));
+    }
+}
@@ -1,62 +1,219 @@
-use point::Point;
+use num_traits::{Num, One, Zero};
+use point::{orientation, Coordinate, Point, Rational128, Transform2, Transform3};
 
+mod clip;
+
+mod distance;
+
+mod linear;
+pub use linear::{HalfPlane, Line, Linear, LinearIntersection, Ray};
+
+mod utils;
+
+/// Segment between two points in `D` dimensions. Defaults to the 2D
+/// integer segments most of the crate was written for.
 #[derive(Clone, Copy, PartialEq, Debug)]
-pub struct LineSegment {
-    pub start: Point<2, i32>,
-    pub end: Point<2, i32>
+pub struct LineSegment<const D: usize = 2, N = i32> {
+    pub start: Point<D, N>,
+    pub end: Point<D, N>
 }
 
+/// Result of intersecting two segments with `N` coordinates. Intersection
+/// points are in `N::Field`: exact rationals for integer segments and the
+/// float type itself for float segments.
 #[derive(Debug, PartialEq)]
-pub enum LineIntersection {
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type", content = "value"))]
+pub enum LineIntersection<P = Rational128, N = i32> {
     None,
-    Point(Point<2, f64>),
-    Overlap(LineSegment)
-}
-
-impl LineSegment {
-    pub fn intersects(&self, other: &Self) -> LineIntersection {
-        let p = self.start;
-        let q = other.start;
-        let r = Point { coords: [self.end.x() - self.start.x(), self.end.y() - self.start.y()] };
-        let s = Point { coords: [other.end.x() - other.start.x(), other.end.y() - other.start.y()] };
-        let r_cross_s = r.cross(&s);
-        let q_minus_p = Point {coords: [q.x() - p.x(), q.y() - p.y()]};
-        let q_minus_p_cross_r = q_minus_p.cross(&r);
-
-        // Parellel
-        if r_cross_s == 0 {
-            if q_minus_p_cross_r == 0 {
-                // Collinear: Check for overlap
-                return self.solve_collinear(other);
-            } else {
-                // Parallel and non-collinear
-                return LineIntersection::None;
-            }
+    Point(Point<2, P>),
+    Overlap(LineSegment<2, N>)
+}
+
+impl<P: Coordinate, N: Copy> LineIntersection<P, N> {
+    /// The same result with the intersection point converted to `f64`.
+    pub fn to_f64(&self) -> LineIntersection<f64, N> {
+        match self {
+            LineIntersection::None => LineIntersection::None,
+            LineIntersection::Point(p) => LineIntersection::Point(p.to_f64()),
+            LineIntersection::Overlap(s) => LineIntersection::Overlap(*s),
         }
+    }
+}
 
-        // Intersection Point
-        // t = (q - p) × s / (r × s)
-        // u = (p - q) × r / (s × r)
-        let t = q_minus_p.cross(&s) as f64 / r_cross_s as f64;
-        let u = q_minus_p_cross_r as f64 / r_cross_s as f64;
+/// Where on a segment an intersection point lies. The single point of a
+/// zero-length segment counts as its `Start`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Location {
+    Start,
+    Interior,
+    End,
+}
 
-        if (0.0..=1.0).contains(&t) && (0.0..=1.0).contains(&u) {
-            let intersect_x = p.x() as f64 + t * r.x() as f64;
-            let intersect_y = p.y() as f64 + t * r.y() as f64;
-            LineIntersection::Point(Point { coords: [intersect_x, intersect_y] })
+impl Location {
+    fn from_parameter<F: Coordinate>(t: F) -> Self {
+        if t.is_zero() {
+            Location::Start
+        } else if t.is_one() {
+            Location::End
         } else {
-            LineIntersection::None
+            Location::Interior
+        }
+    }
+
+    pub fn is_endpoint(&self) -> bool {
+        *self != Location::Interior
+    }
+}
+
+/// How two segments meet at a single point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CrossingKind {
+    /// The interiors cross.
+    Proper,
+    /// An endpoint of one segment lies in the interior of the other.
+    TJunction,
+    /// The segments share an endpoint.
+    Endpoint,
+}
+
+/// A single intersection point of two segments, with its parameters
+/// `point = self.start + t * (self.end - self.start)` and
+/// `point = other.start + u * (other.end - other.start)`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Crossing<P> {
+    pub point: Point<2, P>,
+    pub t: P,
+    pub u: P,
+    pub on_self: Location,
+    pub on_other: Location,
+}
+
+impl<P> Crossing<P> {
+    pub fn kind(&self) -> CrossingKind {
+        match (self.on_self.is_endpoint(), self.on_other.is_endpoint()) {
+            (false, false) => CrossingKind::Proper,
+            (true, true) => CrossingKind::Endpoint,
+            _ => CrossingKind::TJunction,
+        }
+    }
+
+    pub fn is_proper(&self) -> bool {
+        self.kind() == CrossingKind::Proper
+    }
+}
+
+/// `LineIntersection` with the details of a single point intersection.
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type", content = "value"))]
+pub enum SegmentIntersection<P = Rational128, N = i32> {
+    None,
+    Crossing(Crossing<P>),
+    Overlap(LineSegment<2, N>)
+}
+
+impl<P, N> From<SegmentIntersection<P, N>> for LineIntersection<P, N> {
+    fn from(i: SegmentIntersection<P, N>) -> Self {
+        match i {
+            SegmentIntersection::None => LineIntersection::None,
+            SegmentIntersection::Crossing(c) => LineIntersection::Point(c.point),
+            SegmentIntersection::Overlap(s) => LineIntersection::Overlap(s),
         }
     }
+}
 
-    fn solve_collinear(&self, other: &LineSegment) -> LineIntersection {
-        // Sort points so start <= end (lexicographically)
-        fn sort_points(a: Point<2, i32>, b: Point<2, i32>) -> (Point<2, i32>, Point<2, i32>) {
-            if a.coords < b.coords { (a, b) } else { (b, a) }
+impl<N: Coordinate> LineSegment<2, N> {
+    /// ```
+    /// use point::Rational128;
+    /// use segment::{LineIntersection, LineSegment};
+    /// let seg1 = LineSegment { start: [0, 0].into(), end: [3, 1].into() };
+    /// let seg2 = LineSegment { start: [0, 1].into(), end: [1, 0].into() };
+    /// assert_eq!(
+    ///     seg1.intersects(&seg2),
+    ///     LineIntersection::Point([Rational128::new(3, 4), Rational128::new(1, 4)].into())
+    /// );
+    ///
+    /// let seg1: LineSegment<2, f64> = LineSegment { start: [0.0, 0.0].into(), end: [3.0, 1.0].into() };
+    /// let seg2 = LineSegment { start: [0.0, 1.0].into(), end: [1.0, 0.0].into() };
+    /// assert_eq!(seg1.intersects(&seg2), LineIntersection::Point([0.75, 0.25].into()));
+    /// ```
+    pub fn intersects(&self, other: &Self) -> LineIntersection<N::Field, N> {
+        self.intersection(other).into()
+    }
+
+    /// Like `intersects` but a single intersection point comes with its
+    /// parameters along both segments and where it lies on each.
+    /// ```
+    /// use point::Rational128;
+    /// use segment::{CrossingKind, LineSegment, Location, SegmentIntersection};
+    /// let seg1 = LineSegment { start: [0, 0].into(), end: [4, 0].into() };
+    /// let seg2 = LineSegment { start: [1, 0].into(), end: [1, 3].into() };
+    /// match seg1.intersection(&seg2) {
+    ///     SegmentIntersection::Crossing(c) => {
+    ///         assert_eq!((c.t, c.u), (Rational128::new(1, 4), Rational128::from(0)));
+    ///         assert_eq!((c.on_self, c.on_other), (Location::Interior, Location::Start));
+    ///         assert_eq!(c.kind(), CrossingKind::TJunction);
+    ///     }
+    ///     _ => panic!("expected a crossing"),
+    /// }
+    /// ```
+    pub fn intersection(&self, other: &Self) -> SegmentIntersection<N::Field, N> {
+        let p = self.start.to_field();
+        let q = other.start.to_field();
+        let r = self.end.to_field() - p;
+        let s = other.end.to_field() - q;
+
+        // A zero-length segment would otherwise look collinear with anything
+        let is_zero = |v: &Point<2, N::Field>| v.coords.iter().all(|c| c.is_zero());
+        if is_zero(&r) || is_zero(&s) {
+            let point = if is_zero(&r) { self.start } else { other.start };
+            return self.crossing_at(other, point);
         }
 
-        let (s1, e1) = sort_points(self.start, self.end);
-        let (s2, e2) = sort_points(other.start, other.end);
+        let (t, u) = match line_parameters(p, r, q, s) {
+            Parameters::Crossing(t, u) => (t, u),
+            Parameters::Collinear => return self.solve_collinear(other),
+            Parameters::Parallel => return SegmentIntersection::None,
+        };
+        let unit = N::Field::zero()..=N::Field::one();
+
+        if unit.contains(&t) && unit.contains(&u) {
+            SegmentIntersection::Crossing(Crossing {
+                point: Point { coords: [p.x() + t * r.x(), p.y() + t * r.y()] },
+                t,
+                u,
+                on_self: Location::from_parameter(t),
+                on_other: Location::from_parameter(u),
+            })
+        } else {
+            SegmentIntersection::None
+        }
+    }
+
+    /// The crossing at `point`, an endpoint of one of the segments, if it
+    /// lies on both.
+    fn crossing_at(&self, other: &Self, point: Point<2, N>) -> SegmentIntersection<N::Field, N> {
+        if !(self.square_distance_to_point(&point).is_zero() && other.square_distance_to_point(&point).is_zero()) {
+            return SegmentIntersection::None;
+        }
+        let t = self.projection_parameter(&point);
+        let u = other.projection_parameter(&point);
+        SegmentIntersection::Crossing(Crossing {
+            point: point.to_field(),
+            t,
+            u,
+            on_self: Location::from_parameter(t),
+            on_other: Location::from_parameter(u),
+        })
+    }
+
+    fn solve_collinear(&self, other: &Self) -> SegmentIntersection<N::Field, N> {
+        let LineSegment { start: s1, end: e1 } = self.canonical();
+        let LineSegment { start: s2, end: e2 } = other.canonical();
 
         // Find the "max of starts" and "min of ends"
         let overlap_start = if s1.coords > s2.coords { s1 } else { s2 };
@@ -65,20 +222,135 @@ impl LineSegment {
         // Check if the overlap is valid
         if overlap_start.coords < overlap_end.coords {
             // Overlap is a segment
-            LineIntersection::Overlap(LineSegment {
+            SegmentIntersection::Overlap(LineSegment {
                 start: overlap_start,
                 end: overlap_end,
             })
         } else if overlap_start.coords == overlap_end.coords {
             // Overlap is exactly one point
-            LineIntersection::Point(Point {
-                coords: [overlap_start.x() as f64, overlap_start.y() as f64],
-            })
+            self.crossing_at(other, overlap_start)
         } else {
-            LineIntersection::None
+            SegmentIntersection::None
         }
     }
+
+    /// Whether the segments share at least one point. Only compares signs
+    /// of cross products, so it involves no division and is exact for
+    /// integer coordinates.
+    /// ```
+    /// use segment::LineSegment;
+    /// let seg1 = LineSegment { start: [0, 0].into(), end: [4, 4].into() };
+    /// assert!(seg1.intersects_bool(&LineSegment { start: [0, 4].into(), end: [4, 0].into() }));
+    /// assert!(!seg1.intersects_bool(&LineSegment { start: [5, 5].into(), end: [6, 9].into() }));
+    /// ```
+    pub fn intersects_bool(&self, other: &Self) -> bool {
+        let (a, b, c, d) = (&self.start, &self.end, &other.start, &other.end);
+        let o1 = orientation(a, b, c);
+        let o2 = orientation(a, b, d);
+        let o3 = orientation(c, d, a);
+        let o4 = orientation(c, d, b);
+        if o1 != o2 && o3 != o4 && o1.is_ne() && o2.is_ne() && o3.is_ne() && o4.is_ne() {
+            return true;
+        }
+        // Touching and collinear cases: an endpoint of one lies on the other
+        (o1.is_eq() && in_box(a, b, c))
+            || (o2.is_eq() && in_box(a, b, d))
+            || (o3.is_eq() && in_box(c, d, a))
+            || (o4.is_eq() && in_box(c, d, b))
+    }
+}
+
+/// How the lines `p + t * r` and `q + u * s` meet, for nonzero `r` and `s`.
+pub(crate) enum Parameters<F> {
+    /// They cross at parameters `t` and `u`.
+    Crossing(F, F),
+    Collinear,
+    Parallel,
+}
+
+/// Solves `p + t * r = q + u * s`. Segments, rays and lines all meet
+/// through this and differ only in the parameters they accept.
+pub(crate) fn line_parameters<F: Coordinate>(
+    p: Point<2, F>,
+    r: Point<2, F>,
+    q: Point<2, F>,
+    s: Point<2, F>,
+) -> Parameters<F> {
+    let r_cross_s = r.cross(&s);
+    let q_minus_p = q - p;
+    let q_minus_p_cross_r = q_minus_p.cross(&r);
+    if r_cross_s.is_zero() {
+        return if q_minus_p_cross_r.is_zero() { Parameters::Collinear } else { Parameters::Parallel };
+    }
+    // t = (q - p) × s / (r × s)
+    // u = (p - q) × r / (s × r)
+    Parameters::Crossing(q_minus_p.cross(&s) / r_cross_s, q_minus_p_cross_r / r_cross_s)
+}
+
+/// Whether `p`, known to be collinear with `a` and `b`, lies between them.
+fn in_box<N: PartialOrd>(a: &Point<2, N>, b: &Point<2, N>, p: &Point<2, N>) -> bool {
+    (0..2).all(|i| {
+        let (lo, hi) = if a.coords[i] < b.coords[i] { (&a.coords[i], &b.coords[i]) } else { (&b.coords[i], &a.coords[i]) };
+        *lo <= p.coords[i] && p.coords[i] <= *hi
+    })
+}
+
+impl<N: Num + Copy> LineSegment<2, N> {
+    /// Maps both endpoints through an affine transform.
+    /// ```
+    /// use point::Transform2;
+    /// use segment::LineSegment;
+    /// let seg = LineSegment { start: [0, 0].into(), end: [1, 2].into() };
+    /// let moved = seg.transform(&Transform2::translation(1, 1));
+    /// assert_eq!(moved, LineSegment { start: [1, 1].into(), end: [2, 3].into() });
+    /// ```
+    pub fn transform(&self, t: &Transform2<N>) -> Self {
+        LineSegment {
+            start: t.apply(&self.start),
+            end: t.apply(&self.end),
+        }
+    }
+}
+
+impl<N: Num + Copy> LineSegment<3, N> {
+    pub fn transform(&self, t: &Transform3<N>) -> Self {
+        LineSegment {
+            start: t.apply(&self.start),
+            end: t.apply(&self.end),
+        }
+    }
+}
+
+/// Segments are written as a `[start, end]` pair.
+#[cfg(feature = "serde")]
+impl<const D: usize, N: serde::Serialize> serde::Serialize for LineSegment<D, N> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&(&self.start, &self.end), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, const D: usize, N: serde::Deserialize<'de>> serde::Deserialize<'de> for LineSegment<D, N> {
+    fn deserialize<De: serde::Deserializer<'de>>(deserializer: De) -> Result<Self, De::Error> {
+        let (start, end) = serde::Deserialize::deserialize(deserializer)?;
+        Ok(LineSegment { start, end })
+    }
+}
+
+#[cfg(feature = "geo-types")]
+impl<N: geo_types::CoordNum> From<geo_types::Line<N>> for LineSegment<2, N> {
+    fn from(line: geo_types::Line<N>) -> Self {
+        LineSegment { start: line.start.into(), end: line.end.into() }
+    }
 }
+
+#[cfg(feature = "geo-types")]
+impl<N: geo_types::CoordNum> From<LineSegment<2, N>> for geo_types::Line<N> {
+    fn from(s: LineSegment<2, N>) -> Self {
+        geo_types::Line::new(s.start, s.end)
+    }
+}
+
 pub fn add(left: u64, right: u64) -> u64 {
     left + right
 }
@@ -86,6 +358,7 @@ pub fn add(left: u64, right: u64) -> u64 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use point::Rational128;
 
     #[test]
     fn intersection_point() {
@@ -97,7 +370,7 @@ mod tests {
             start: [0, 2].into(),
             end: [2, 0].into() 
         };
-        assert_eq!(seg1.intersects(&seg2), LineIntersection::Point([1.0, 1.0].into()) )
+        assert_eq!(seg1.intersects(&seg2).to_f64(), LineIntersection::Point([1.0, 1.0].into()) )
     }
      #[test]
     fn intersection_end() {
@@ -109,7 +382,7 @@ mod tests {
             start: [2, 2].into(),
             end: [2, 4].into() 
         };
-        assert_eq!(seg1.intersects(&seg2), LineIntersection::Point([2.0, 2.0].into()) )
+        assert_eq!(seg1.intersects(&seg2).to_f64(), LineIntersection::Point([2.0, 2.0].into()) )
     }
     #[test]
     fn intersection_none() {
@@ -146,6 +419,183 @@ mod tests {
             start: [4, 4].into(),
             end: [5, 5].into() 
         };
-        assert_eq!(seg1.intersects(&seg2), LineIntersection::Point([4.0, 4.0].into()) )
+        assert_eq!(seg1.intersects(&seg2).to_f64(), LineIntersection::Point([4.0, 4.0].into()) )
+    }
+
+    #[test]
+    fn intersection_exact() {
+        let seg1 = LineSegment {
+            start: [0, 0].into(),
+            end: [3, 0].into()
+        };
+        let seg2 = LineSegment {
+            start: [1, -1].into(),
+            end: [2, 2].into()
+        };
+        let third = Rational128::new(1, 3);
+        assert_eq!(seg1.intersects(&seg2), LineIntersection::Point([Rational128::from(1) + third, Rational128::from(0)].into()));
+        assert_eq!(seg1.intersects(&seg2).to_f64(), LineIntersection::Point([4.0 / 3.0, 0.0].into()));
+    }
+
+    #[test]
+    fn intersection_near_bound() {
+        // Coordinates just under 2^30, where the point's numerators need
+        // about 96 bits
+        let m = (1 << 30) - 1;
+        let seg1 = LineSegment { start: [-m, 1 - m].into(), end: [m, m - 3].into() };
+        let seg2 = LineSegment { start: [5 - m, m].into(), end: [m - 7, 2 - m].into() };
+        let SegmentIntersection::Crossing(c) = seg1.intersection(&seg2) else { panic!("expected a crossing") };
+        let along = |seg: &LineSegment, t: Rational128| {
+            let (p, q) = (seg.start.to_field(), seg.end.to_field());
+            Point::from([p.x() + t * (q.x() - p.x()), p.y() + t * (q.y() - p.y())])
+        };
+        assert_eq!(along(&seg1, c.t), c.point);
+        assert_eq!(along(&seg2, c.u), c.point);
+        assert!(c.is_proper());
+        assert!(c.point.x().denom() > 1 << 40);
     }
-}
\ No newline at end of file
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_json() {
+        let seg = LineSegment { start: [0, 1].into(), end: [2, 3].into() };
+        assert_eq!(serde_json::to_string(&seg).unwrap(), "[[0,1],[2,3]]");
+        assert_eq!(serde_json::from_str::<LineSegment>("[[0,1],[2,3]]").unwrap(), seg);
+
+        let point: LineIntersection<f64> = LineIntersection::Point([0.5, 1.0].into());
+        let json = serde_json::to_string(&point).unwrap();
+        assert_eq!(json, r#"{"type":"Point","value":[0.5,1.0]}"#);
+        assert_eq!(serde_json::from_str::<LineIntersection<f64>>(&json).unwrap(), point);
+
+        let overlap: LineIntersection<f64> = LineIntersection::Overlap(seg);
+        assert_eq!(serde_json::to_string(&overlap).unwrap(), r#"{"type":"Overlap","value":[[0,1],[2,3]]}"#);
+        assert_eq!(serde_json::to_string(&LineIntersection::<f64>::None).unwrap(), r#"{"type":"None"}"#);
+        assert_eq!(serde_json::from_str::<LineIntersection<f64>>(r#"{"type":"None"}"#).unwrap(), LineIntersection::None);
+
+        let exact = seg.intersects(&LineSegment { start: [0, 3].into(), end: [1, 1].into() });
+        let json = serde_json::to_string(&exact).unwrap();
+        assert_eq!(json, r#"{"type":"Point","value":[[2,3],[5,3]]}"#);
+        assert_eq!(serde_json::from_str::<LineIntersection<Rational128>>(&json).unwrap(), exact);
+    }
+
+    #[cfg(feature = "geo-types")]
+    #[test]
+    fn geo_types_line() {
+        let seg = LineSegment { start: [0, 1].into(), end: [2, 3].into() };
+        let line: geo_types::Line<i32> = seg.into();
+        assert_eq!(line, geo_types::Line::new((0, 1), (2, 3)));
+        assert_eq!(LineSegment::from(line), seg);
+    }
+
+    #[test]
+    fn intersection_exact_collinear() {
+        let seg1 = LineSegment {
+            start: [0, 0].into(),
+            end: [4, 4].into()
+        };
+        let seg2 = LineSegment {
+            start: [4, 4].into(),
+            end: [5, 5].into()
+        };
+        assert_eq!(seg1.intersects(&seg2), LineIntersection::Point([Rational128::from(4), Rational128::from(4)].into()));
+        let seg3 = LineSegment {
+            start: [5, 6].into(),
+            end: [6, 7].into()
+        };
+        assert_eq!(seg1.intersects(&seg3), LineIntersection::None);
+    }
+
+    #[test]
+    fn intersection_float() {
+        let seg1: LineSegment<2, f64> = LineSegment {
+            start: [0.0, 0.0].into(),
+            end: [1.0, 1.0].into()
+        };
+        let seg2 = LineSegment {
+            start: [0.0, 1.0].into(),
+            end: [1.0, 0.0].into()
+        };
+        assert_eq!(seg1.intersects(&seg2), LineIntersection::Point([0.5, 0.5].into()));
+        let seg3 = LineSegment {
+            start: [0.5, 0.5].into(),
+            end: [2.0, 2.0].into()
+        };
+        assert_eq!(seg1.intersects(&seg3), LineIntersection::Overlap(LineSegment { start: [0.5, 0.5].into(), end: [1.0, 1.0].into() }));
+    }
+
+    #[test]
+    fn intersection_wide_integers() {
+        // Cross products here overflow i64, so i64 segments use 128-bit rationals
+        let big = 1i64 << 40;
+        let seg1: LineSegment<2, i64> = LineSegment {
+            start: [-big, -big].into(),
+            end: [big, big].into()
+        };
+        let seg2 = LineSegment {
+            start: [-big, big].into(),
+            end: [big, -big + 1].into()
+        };
+        let p = match seg1.intersects(&seg2) {
+            LineIntersection::Point(p) => p,
+            other => panic!("expected a point, got {other:?}"),
+        };
+        assert_eq!(p.x(), p.y());
+        assert_eq!(p.x(), Rational128::new(big as i128, 4 * big as i128 - 1));
+    }
+
+    #[test]
+    fn transform_3d() {
+        let seg: LineSegment<3, i32> = LineSegment { start: [0, 0, 0].into(), end: [1, 2, 3].into() };
+        let moved = seg.transform(&Transform3::translation(1, 0, -1));
+        assert_eq!(moved, LineSegment { start: [1, 0, -1].into(), end: [2, 2, 2].into() });
+    }
+
+    #[test]
+    fn intersection_zero_length() {
+        let dot = LineSegment { start: [1, 1].into(), end: [1, 1].into() };
+        let below = LineSegment { start: [0, 0].into(), end: [2, 0].into() };
+        assert_eq!(dot.intersects(&below), LineIntersection::None);
+        assert_eq!(below.intersects(&dot), LineIntersection::None);
+        let through = LineSegment { start: [0, 0].into(), end: [3, 3].into() };
+        assert_eq!(dot.intersects(&through), LineIntersection::Point([Rational128::from(1), Rational128::from(1)].into()));
+        assert_eq!(dot.intersects(&dot), LineIntersection::Point([Rational128::from(1), Rational128::from(1)].into()));
+    }
+
+    #[test]
+    fn crossing_classification() {
+        let seg1 = LineSegment { start: [0, 0].into(), end: [4, 4].into() };
+        let kind = |other: LineSegment| match seg1.intersection(&other) {
+            SegmentIntersection::Crossing(c) => Some(c.kind()),
+            _ => None,
+        };
+        assert_eq!(kind(LineSegment { start: [0, 4].into(), end: [4, 0].into() }), Some(CrossingKind::Proper));
+        assert_eq!(kind(LineSegment { start: [2, 2].into(), end: [4, 0].into() }), Some(CrossingKind::TJunction));
+        assert_eq!(kind(LineSegment { start: [4, 4].into(), end: [4, 0].into() }), Some(CrossingKind::Endpoint));
+        // Collinear segments touching end to end
+        assert_eq!(kind(LineSegment { start: [5, 5].into(), end: [4, 4].into() }), Some(CrossingKind::Endpoint));
+        assert_eq!(kind(LineSegment { start: [5, 5].into(), end: [6, 9].into() }), None);
+
+        match seg1.intersection(&LineSegment { start: [6, 6].into(), end: [4, 4].into() }) {
+            SegmentIntersection::Crossing(c) => {
+                assert_eq!((c.t, c.u), (Rational128::from(1), Rational128::from(1)));
+                assert_eq!((c.on_self, c.on_other), (Location::End, Location::End));
+            }
+            other => panic!("expected a crossing, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn intersects_bool_agrees() {
+        // Every segment between points of a small grid against every other
+        let grid: Vec<Point<2, i32>> = (0..3).flat_map(|x| (0..3).map(move |y| [x, y].into())).collect();
+        let segments: Vec<LineSegment> = grid
+            .iter()
+            .flat_map(|&start| grid.iter().map(move |&end| LineSegment { start, end }))
+            .collect();
+        for a in &segments {
+            for b in &segments {
+                assert_eq!(a.intersects_bool(b), a.intersects(b) != LineIntersection::None, "{a:?} {b:?}");
+            }
+        }
+    }
+}
@@ -0,0 +1,405 @@
+use crate::{line_parameters, LineSegment, Parameters};
+use num_traits::{One, Zero};
+use point::{Coordinate, Point};
+use std::cmp::Ordering;
+
+/// Infinite line through `point` along `direction`. The direction gives
+/// the line an orientation, so it has a left and a right side.
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Line<N = i32> {
+    pub point: Point<2, N>,
+    pub direction: Point<2, N>,
+}
+
+/// Half-infinite line starting at `origin` and going along `direction`.
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Ray<N = i32> {
+    pub origin: Point<2, N>,
+    pub direction: Point<2, N>,
+}
+
+/// The closed half-plane to the left of a directed boundary line.
+/// ```
+/// use segment::{HalfPlane, Line};
+/// // Everything with y >= 1
+/// let h = HalfPlane::new(Line::through([0, 1].into(), [1, 1].into()));
+/// assert!(h.contains(&[5, 3].into()));
+/// assert!(h.contains(&[-2, 1].into()));
+/// assert!(!h.contains(&[0, 0].into()));
+/// ```
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HalfPlane<N = i32> {
+    pub boundary: Line<N>,
+}
+
+/// Result of intersecting two of `Line`, `Ray` and `LineSegment`. A single
+/// crossing point is in `N::Field`; overlaps of collinear inputs are made
+/// of their original endpoints and keep the coordinate type.
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type", content = "value"))]
+pub enum LinearIntersection<P, N = i32> {
+    None,
+    Point(Point<2, P>),
+    Segment(LineSegment<2, N>),
+    Ray(Ray<N>),
+    Line(Line<N>),
+}
+
+impl<P, N> From<crate::LineIntersection<P, N>> for LinearIntersection<P, N> {
+    fn from(i: crate::LineIntersection<P, N>) -> Self {
+        match i {
+            crate::LineIntersection::None => LinearIntersection::None,
+            crate::LineIntersection::Point(p) => LinearIntersection::Point(p),
+            crate::LineIntersection::Overlap(s) => LinearIntersection::Segment(s),
+        }
+    }
+}
+
+/// The common parametric form `origin + t * direction` of lines, rays and
+/// segments. `t` is unbounded for a line, at least 0 for a ray and in
+/// `[0, 1]` for a segment.
+pub trait Linear<N> {
+    /// The point at `t = 0`.
+    fn origin(&self) -> Point<2, N>;
+
+    /// The point at `t = 1` minus the origin.
+    fn direction(&self) -> Point<2, N>;
+
+    /// Whether `t` is bounded below by 0.
+    fn has_origin(&self) -> bool;
+
+    /// The point at `t = 1` if `t` is bounded above by 1.
+    fn end(&self) -> Option<Point<2, N>>;
+}
+
+impl<N: Coordinate> Linear<N> for Line<N> {
+    fn origin(&self) -> Point<2, N> {
+        self.point
+    }
+
+    fn direction(&self) -> Point<2, N> {
+        self.direction
+    }
+
+    fn has_origin(&self) -> bool {
+        false
+    }
+
+    fn end(&self) -> Option<Point<2, N>> {
+        None
+    }
+}
+
+impl<N: Coordinate> Linear<N> for Ray<N> {
+    fn origin(&self) -> Point<2, N> {
+        self.origin
+    }
+
+    fn direction(&self) -> Point<2, N> {
+        self.direction
+    }
+
+    fn has_origin(&self) -> bool {
+        true
+    }
+
+    fn end(&self) -> Option<Point<2, N>> {
+        None
+    }
+}
+
+impl<N: Coordinate> Linear<N> for LineSegment<2, N> {
+    fn origin(&self) -> Point<2, N> {
+        self.start
+    }
+
+    fn direction(&self) -> Point<2, N> {
+        self.end - self.start
+    }
+
+    fn has_origin(&self) -> bool {
+        true
+    }
+
+    fn end(&self) -> Option<Point<2, N>> {
+        Some(self.end)
+    }
+}
+
+fn cross_sign<F: Coordinate>(v: F) -> Ordering {
+    v.partial_cmp(&F::zero()).unwrap_or(Ordering::Equal)
+}
+
+impl<N: Coordinate> Line<N> {
+    pub fn new(point: Point<2, N>, direction: Point<2, N>) -> Self {
+        Self { point, direction }
+    }
+
+    /// The line through `a` and `b`, directed from `a` to `b`.
+    pub fn through(a: Point<2, N>, b: Point<2, N>) -> Self {
+        Self { point: a, direction: b - a }
+    }
+
+    /// `Greater` left of the line, `Less` right of it and `Equal` on it.
+    pub fn side(&self, p: &Point<2, N>) -> Ordering {
+        cross_sign(self.direction.to_field().cross(&(p.to_field() - self.point.to_field())))
+    }
+
+    pub fn contains(&self, p: &Point<2, N>) -> bool {
+        self.side(p) == Ordering::Equal
+    }
+
+    /// The same line with the opposite direction.
+    pub fn reversed(&self) -> Self {
+        Self { point: self.point, direction: negate(&self.direction) }
+    }
+
+    /// ```
+    /// use point::Rational128;
+    /// use segment::{Line, LinearIntersection, Ray};
+    /// let line = Line::through([0, 0].into(), [2, 1].into());
+    /// let ray = Ray::through([3, 0].into(), [3, 1].into());
+    /// assert_eq!(
+    ///     line.intersects(&ray),
+    ///     LinearIntersection::Point([Rational128::from(3), Rational128::new(3, 2)].into())
+    /// );
+    /// ```
+    pub fn intersects<T: Linear<N>>(&self, other: &T) -> LinearIntersection<N::Field, N> {
+        intersect(self, other)
+    }
+}
+
+impl<N: Coordinate> Ray<N> {
+    pub fn new(origin: Point<2, N>, direction: Point<2, N>) -> Self {
+        Self { origin, direction }
+    }
+
+    /// The ray from `origin` through `toward`.
+    pub fn through(origin: Point<2, N>, toward: Point<2, N>) -> Self {
+        Self { origin, direction: toward - origin }
+    }
+
+    pub fn contains(&self, p: &Point<2, N>) -> bool {
+        contains(self, &p.to_field())
+    }
+
+    pub fn intersects<T: Linear<N>>(&self, other: &T) -> LinearIntersection<N::Field, N> {
+        intersect(self, other)
+    }
+}
+
+impl<N: Coordinate> LineSegment<2, N> {
+    /// Intersection with a `Line`, `Ray` or another segment. For two
+    /// segments this agrees with `intersects` except that an overlap is
+    /// oriented along `self` rather than lexicographically.
+    pub fn intersects_linear<T: Linear<N>>(&self, other: &T) -> LinearIntersection<N::Field, N> {
+        intersect(self, other)
+    }
+}
+
+impl<N: Coordinate> HalfPlane<N> {
+    pub fn new(boundary: Line<N>) -> Self {
+        Self { boundary }
+    }
+
+    /// `Greater` strictly inside, `Equal` on the boundary and `Less` outside.
+    pub fn side(&self, p: &Point<2, N>) -> Ordering {
+        self.boundary.side(p)
+    }
+
+    pub fn contains(&self, p: &Point<2, N>) -> bool {
+        self.side(p) != Ordering::Less
+    }
+
+    /// The closed half-plane on the other side of the same boundary.
+    pub fn flip(&self) -> Self {
+        Self { boundary: self.boundary.reversed() }
+    }
+}
+
+fn negate<N: Coordinate>(v: &Point<2, N>) -> Point<2, N> {
+    Point { coords: v.coords.map(|c| N::zero() - c) }
+}
+
+fn is_zero<F: Coordinate>(v: &Point<2, F>) -> bool {
+    v.coords.iter().all(|c| c.is_zero())
+}
+
+/// Whether parameter `t` of `a` is within its bounds.
+fn in_range<N: Coordinate, A: Linear<N>>(a: &A, t: N::Field) -> bool {
+    (!a.has_origin() || t >= N::Field::zero()) && (a.end().is_none() || t <= N::Field::one())
+}
+
+/// Whether `p` lies on `a`, `p` being in field coordinates.
+fn contains<N: Coordinate, A: Linear<N>>(a: &A, p: &Point<2, N::Field>) -> bool {
+    let o = a.origin().to_field();
+    let d = a.direction().to_field();
+    let v = *p - o;
+    if is_zero(&d) {
+        return is_zero(&v);
+    }
+    if !d.cross(&v).is_zero() {
+        return false;
+    }
+    let t = v.dot(&d);
+    (!a.has_origin() || t >= N::Field::zero()) && a.end().is_none_or(|_| t <= d.dot(&d))
+}
+
+fn intersect<N: Coordinate, A: Linear<N>, B: Linear<N>>(a: &A, b: &B) -> LinearIntersection<N::Field, N> {
+    let p = a.origin().to_field();
+    let q = b.origin().to_field();
+    let r = a.direction().to_field();
+    let s = b.direction().to_field();
+
+    // Zero-length segments are single points
+    if is_zero(&r) {
+        return if contains(b, &p) { LinearIntersection::Point(p) } else { LinearIntersection::None };
+    }
+    if is_zero(&s) {
+        return if contains(a, &q) { LinearIntersection::Point(q) } else { LinearIntersection::None };
+    }
+
+    let (t, u) = match line_parameters(p, r, q, s) {
+        Parameters::Crossing(t, u) => (t, u),
+        Parameters::Collinear => return collinear(a, b),
+        Parameters::Parallel => return LinearIntersection::None,
+    };
+    // The same solve as for two segments, with each parameter held to the
+    // range its own type allows
+    if in_range(a, t) && in_range(b, u) {
+        LinearIntersection::Point(Point { coords: [p.x() + t * r.x(), p.y() + t * r.y()] })
+    } else {
+        LinearIntersection::None
+    }
+}
+
+/// Overlap of two collinear objects. Positions along the shared line are
+/// measured as `(x - a.origin) · a.direction`, which orders points the same
+/// way as `a`'s parameter without dividing.
+fn collinear<N: Coordinate, A: Linear<N>, B: Linear<N>>(a: &A, b: &B) -> LinearIntersection<N::Field, N> {
+    let p = a.origin().to_field();
+    let r = a.direction().to_field();
+    let position = |x: &Point<2, N>| (x.to_field() - p).dot(&r);
+
+    // Each bound is a position and the original point it comes from
+    let mut lower = a.has_origin().then(|| (N::Field::zero(), a.origin()));
+    let mut upper = a.end().map(|e| (position(&e), e));
+
+    let b_start = b.has_origin().then(|| (position(&b.origin()), b.origin()));
+    let b_end = b.end().map(|e| (position(&e), e));
+    let (b_lower, b_upper) = if b.direction().to_field().dot(&r) > N::Field::zero() {
+        (b_start, b_end)
+    } else {
+        (b_end, b_start)
+    };
+    if let Some(bl) = b_lower {
+        if lower.is_none_or(|l| bl.0 > l.0) {
+            lower = Some(bl);
+        }
+    }
+    if let Some(bu) = b_upper {
+        if upper.is_none_or(|u| bu.0 < u.0) {
+            upper = Some(bu);
+        }
+    }
+
+    match (lower, upper) {
+        (None, None) => LinearIntersection::Line(Line::new(a.origin(), a.direction())),
+        (Some((_, start)), None) => LinearIntersection::Ray(Ray::new(start, a.direction())),
+        (None, Some((_, end))) => LinearIntersection::Ray(Ray::new(end, negate(&a.direction()))),
+        (Some((lo, start)), Some((hi, end))) => match lo.partial_cmp(&hi) {
+            Some(Ordering::Less) => LinearIntersection::Segment(LineSegment { start, end }),
+            Some(Ordering::Equal) => LinearIntersection::Point(start.to_field()),
+            _ => LinearIntersection::None,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use point::Rational128;
+
+    fn r(n: i128) -> Rational128 {
+        Rational128::from(n)
+    }
+
+    #[test]
+    fn crossing() {
+        let line = Line::through([0, 0].into(), [1, 1].into());
+        let ray = Ray::new([4, 0].into(), [-1, 1].into());
+        assert_eq!(line.intersects(&ray), LinearIntersection::Point(Point { coords: [r(2), r(2)] }));
+        // Pointing away from the line
+        let away = Ray::new([4, 0].into(), [1, -1].into());
+        assert_eq!(line.intersects(&away), LinearIntersection::None);
+        assert_eq!(away.intersects(&line), LinearIntersection::None);
+
+        let seg = LineSegment { start: [0, 3].into(), end: [1, 2].into() };
+        assert_eq!(line.intersects(&seg), LinearIntersection::None);
+        assert_eq!(ray.intersects(&seg), LinearIntersection::None);
+        assert_eq!(Line::through([0, 3].into(), [1, 2].into()).intersects(&line), LinearIntersection::Point(Point { coords: [Rational128::new(3, 2), Rational128::new(3, 2)] }));
+    }
+
+    #[test]
+    fn parallel() {
+        let line = Line::through([0, 0].into(), [1, 1].into());
+        let other = Line::through([0, 1].into(), [1, 2].into());
+        assert_eq!(line.intersects(&other), LinearIntersection::None);
+        assert_eq!(line.intersects(&line.reversed()), LinearIntersection::Line(line));
+    }
+
+    #[test]
+    fn collinear_overlaps() {
+        let line = Line::through([0, 0].into(), [1, 0].into());
+        let ray = Ray::new([2, 0].into(), [-3, 0].into());
+        assert_eq!(line.intersects(&ray), LinearIntersection::Ray(Ray::new([2, 0].into(), [-1, 0].into())));
+        assert_eq!(ray.intersects(&line), LinearIntersection::Ray(ray));
+
+        // Opposite rays overlap in a segment, touch at a point, or miss
+        let back = Ray::new([-1, 0].into(), [1, 0].into());
+        assert_eq!(ray.intersects(&back), LinearIntersection::Segment(LineSegment { start: [2, 0].into(), end: [-1, 0].into() }));
+        let touching = Ray::new([2, 0].into(), [5, 0].into());
+        assert_eq!(ray.intersects(&touching), LinearIntersection::Point(Point { coords: [r(2), r(0)] }));
+        let missing = Ray::new([3, 0].into(), [1, 0].into());
+        assert_eq!(ray.intersects(&missing), LinearIntersection::None);
+
+        let seg = LineSegment { start: [5, 0].into(), end: [-4, 0].into() };
+        assert_eq!(ray.intersects(&seg), LinearIntersection::Segment(LineSegment { start: [2, 0].into(), end: [-4, 0].into() }));
+        assert_eq!(line.intersects(&seg), LinearIntersection::Segment(LineSegment { start: [-4, 0].into(), end: [5, 0].into() }));
+        assert_eq!(seg.intersects_linear(&line), LinearIntersection::Segment(seg));
+    }
+
+    #[test]
+    fn zero_length_segment() {
+        let ray = Ray::new([0, 0].into(), [2, 1].into());
+        let on = LineSegment { start: [4, 2].into(), end: [4, 2].into() };
+        let off = LineSegment { start: [-4, -2].into(), end: [-4, -2].into() };
+        assert_eq!(ray.intersects(&on), LinearIntersection::Point(Point { coords: [r(4), r(2)] }));
+        assert_eq!(ray.intersects(&off), LinearIntersection::None);
+        assert!(ray.contains(&[6, 3].into()));
+        assert!(!ray.contains(&[-2, -1].into()));
+    }
+
+    #[test]
+    fn segments_agree() {
+        let a = LineSegment { start: [0, 0].into(), end: [4, 4].into() };
+        let b = LineSegment { start: [5, 5].into(), end: [1, 1].into() };
+        assert_eq!(a.intersects_linear(&b), a.intersects(&b).into());
+        let c = LineSegment { start: [0, 4].into(), end: [4, 0].into() };
+        assert_eq!(a.intersects_linear(&c), a.intersects(&c).into());
+    }
+
+    #[test]
+    fn half_plane() {
+        let h: HalfPlane<f64> = HalfPlane::new(Line::new([0.0, 0.0].into(), [1.0, 1.0].into()));
+        assert_eq!(h.side(&[0.0, 1.0].into()), Ordering::Greater);
+        assert_eq!(h.side(&[2.0, 2.0].into()), Ordering::Equal);
+        assert!(!h.contains(&[1.0, 0.0].into()));
+        assert!(h.flip().contains(&[1.0, 0.0].into()));
+        assert!(h.flip().contains(&[2.0, 2.0].into()));
+    }
+}
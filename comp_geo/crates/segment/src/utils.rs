@@ -0,0 +1,142 @@
+use crate::LineSegment;
+use num_traits::{One, Zero};
+use point::{Aabb, Coordinate, Point};
+
+impl<const D: usize, N: Coordinate> LineSegment<D, N> {
+    pub fn new(start: Point<D, N>, end: Point<D, N>) -> Self {
+        Self { start, end }
+    }
+
+    /// `end - start`.
+    pub fn direction(&self) -> Point<D, N> {
+        self.end - self.start
+    }
+
+    /// In the wide type, like `orientation`, so integer lengths cannot
+    /// overflow.
+    pub fn square_length(&self) -> N::Wide {
+        let d = self.end.widen() - self.start.widen();
+        d.dot(&d)
+    }
+
+    pub fn length(&self) -> f64 {
+        self.square_length().to_f64().sqrt()
+    }
+
+    /// `start + t * (end - start)`, exact for integer segments.
+    pub fn point_at(&self, t: N::Field) -> Point<D, N::Field> {
+        let (a, b) = (self.start.to_field(), self.end.to_field());
+        Point { coords: std::array::from_fn(|i| a.coords[i] + (b.coords[i] - a.coords[i]) * t) }
+    }
+
+    /// ```
+    /// use point::Rational128;
+    /// use segment::LineSegment;
+    /// let seg = LineSegment { start: [0, 0].into(), end: [3, 2].into() };
+    /// assert_eq!(seg.midpoint(), [Rational128::new(3, 2), Rational128::from(1)].into());
+    /// ```
+    pub fn midpoint(&self) -> Point<D, N::Field> {
+        let one = N::Field::one();
+        self.point_at(one / (one + one))
+    }
+
+    pub fn reversed(&self) -> Self {
+        Self { start: self.end, end: self.start }
+    }
+
+    /// The same segment with `start <= end` lexicographically, the order
+    /// overlaps are reported in by `intersects`.
+    pub fn canonical(&self) -> Self {
+        if self.is_canonical() { *self } else { self.reversed() }
+    }
+
+    pub fn is_canonical(&self) -> bool {
+        self.start.coords <= self.end.coords
+    }
+
+    /// Whether `p` lies on the segment, endpoints included. Exact for
+    /// integer coordinates.
+    pub fn contains_point(&self, p: &Point<D, N>) -> bool {
+        self.square_distance_to_point(p).is_zero()
+    }
+
+    /// The two halves on either side of `point_at(t)`, or `None` when `t`
+    /// is outside `[0, 1]`.
+    /// ```
+    /// use point::Rational128;
+    /// use segment::LineSegment;
+    /// let seg = LineSegment { start: [0, 0].into(), end: [4, 2].into() };
+    /// let [a, b] = seg.split_at(Rational128::new(1, 4)).unwrap();
+    /// assert_eq!(a.end, [Rational128::from(1), Rational128::new(1, 2)].into());
+    /// assert_eq!(a.end, b.start);
+    /// ```
+    pub fn split_at(&self, t: N::Field) -> Option<[LineSegment<D, N::Field>; 2]> {
+        if t < N::Field::zero() || t > N::Field::one() {
+            return None;
+        }
+        let mid = self.point_at(t);
+        Some([
+            LineSegment { start: self.start.to_field(), end: mid },
+            LineSegment { start: mid, end: self.end.to_field() },
+        ])
+    }
+
+    /// Splits at a point of the segment, keeping the coordinate type.
+    /// `None` if `p` is not on the segment.
+    pub fn split_at_point(&self, p: &Point<D, N>) -> Option<[Self; 2]> {
+        self.contains_point(p)
+            .then_some([Self { start: self.start, end: *p }, Self { start: *p, end: self.end }])
+    }
+
+    pub fn bounding_box(&self) -> Aabb<D, N> {
+        Aabb::new(self.start, self.end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use point::Rational128;
+
+    #[test]
+    fn measures() {
+        let seg = LineSegment::new([1, 1].into(), [4, 5].into());
+        assert_eq!(seg.direction(), [3, 4].into());
+        assert_eq!(seg.square_length(), 25_i64);
+        let long = LineSegment::new([-1 << 30, 0].into(), [1 << 30, 1 << 30].into());
+        assert_eq!(long.square_length(), 5_i64 << 60);
+        assert_eq!(seg.length(), 5.0);
+        assert_eq!(seg.bounding_box(), Aabb { min: [1, 1].into(), max: [4, 5].into() });
+        assert_eq!(seg.reversed().bounding_box(), seg.bounding_box());
+
+        let seg: LineSegment<3, f64> = LineSegment::new([0.0, 0.0, 0.0].into(), [1.0, 2.0, 2.0].into());
+        assert_eq!(seg.length(), 3.0);
+        assert_eq!(seg.midpoint(), [0.5, 1.0, 1.0].into());
+    }
+
+    #[test]
+    fn canonical() {
+        let seg = LineSegment::new([2, 0].into(), [1, 5].into());
+        assert!(!seg.is_canonical());
+        assert_eq!(seg.canonical(), LineSegment::new([1, 5].into(), [2, 0].into()));
+        assert_eq!(seg.canonical().canonical(), seg.canonical());
+    }
+
+    #[test]
+    fn containment_and_splits() {
+        let seg = LineSegment::new([0, 0].into(), [6, 3].into());
+        assert!(seg.contains_point(&[2, 1].into()));
+        assert!(seg.contains_point(&[6, 3].into()));
+        assert!(!seg.contains_point(&[8, 4].into()));
+        assert!(!seg.contains_point(&[2, 2].into()));
+
+        let [a, b] = seg.split_at_point(&[4, 2].into()).unwrap();
+        assert_eq!((a.end, b.start), ([4, 2].into(), [4, 2].into()));
+        assert_eq!(seg.split_at_point(&[1, 1].into()), None);
+
+        assert_eq!(seg.split_at(Rational128::from(2)), None);
+        let [a, b] = seg.split_at(Rational128::new(1, 3)).unwrap();
+        assert_eq!(a.end, Point { coords: [Rational128::from(2), Rational128::from(1)] });
+        assert_eq!(b.end, seg.end.to_field());
+    }
+}